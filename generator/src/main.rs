@@ -4,7 +4,7 @@ use std::{
     collections::{HashMap, HashSet},
     env,
     fs::File,
-    io::{Seek, SeekFrom, Write},
+    io::{Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
     rc::Rc,
 };
@@ -28,8 +28,12 @@ fn main() {
             .join("../sys/OpenXR-SDK/specification/registry/xr.xml")
     }))
     .expect("failed to open registry XML file");
-    // Hack around leading garbage in 1.0 revision of XML
-    source.seek(SeekFrom::Start(3)).unwrap();
+    // Skip the UTF-8 byte order mark present in some revisions of the XML
+    let mut bom = [0; 3];
+    source.read_exact(&mut bom).unwrap();
+    if bom != [0xef, 0xbb, 0xbf] {
+        source.seek(SeekFrom::Start(0)).unwrap();
+    }
 
     let mut parser = Parser::new(source);
     parser.parse();
@@ -46,7 +50,13 @@ struct Parser {
     /// *FlagBits -> *Flags
     bitvalues: HashMap<String, String>,
     handles: IndexSet<String>,
+    /// Extension-defined opaque 64-bit identifiers
+    atoms: IndexSet<String>,
+    /// Extension-defined aliases of primitive types
+    basetypes: IndexMap<String, String>,
     structs: IndexMap<String, Struct>,
+    /// Alternate name -> canonical name
+    struct_aliases: IndexMap<String, String>,
     api_constants: Vec<(String, usize)>,
     commands: IndexMap<String, Command>,
     extensions: IndexMap<String, Tag>,
@@ -66,7 +76,10 @@ impl Parser {
             bitmasks: IndexMap::new(),
             bitvalues: HashMap::new(),
             handles: IndexSet::new(),
+            atoms: IndexSet::new(),
+            basetypes: IndexMap::new(),
             structs: IndexMap::new(),
+            struct_aliases: IndexMap::new(),
             api_constants: Vec::new(),
             commands: IndexMap::new(),
             extensions: IndexMap::new(),
@@ -98,6 +111,9 @@ impl Parser {
                     "extensions" => {
                         self.parse_extensions();
                     }
+                    "feature" => {
+                        self.parse_feature(&attributes);
+                    }
                     _ => {
                         eprintln!("unimplemented root element: {}", name.local_name);
                         self.finish_element();
@@ -109,6 +125,34 @@ impl Parser {
                 _ => {}
             }
         }
+        for &name in LOADER_TYPES {
+            self.structs.shift_remove(name);
+            self.enums.shift_remove(name);
+        }
+        self.resolve_array_lengths();
+    }
+
+    /// Replace array lengths that refer to enum values, e.g. `XR_EYE_POSITION_COUNT_FB`, with
+    /// their numeric value
+    fn resolve_array_lengths(&mut self) {
+        let enums = &self.enums;
+        let api_constants = &self.api_constants;
+        for member in self.structs.values_mut().flat_map(|s| s.members.iter_mut()) {
+            let len = match member.static_array_len {
+                Some(ref mut x) => x,
+                None => continue,
+            };
+            if len.parse::<usize>().is_ok() || api_constants.iter().any(|x| x.0 == *len) {
+                continue;
+            }
+            let value = enums
+                .values()
+                .flat_map(|e| e.values.iter())
+                .find(|x| x.name == *len)
+                .unwrap_or_else(|| panic!("unknown array length {}", len))
+                .value;
+            *len = value.to_string();
+        }
     }
 
     fn parse_tags(&mut self) {
@@ -206,7 +250,36 @@ impl Parser {
                         }
                         "enum" => {
                             let name = attr(&attributes, "name").unwrap();
-                            if let Some(extends) = attr(&attributes, "extends") {
+                            if let (Some(extends), Some(alias)) =
+                                (attr(&attributes, "extends"), attr(&attributes, "alias"))
+                            {
+                                let comment = attr(&attributes, "comment").and_then(tidy_comment);
+                                match self.bitvalues.get(extends) {
+                                    Some(flags) => self
+                                        .bitmasks
+                                        .get_mut(flags)
+                                        .unwrap()
+                                        .push_alias(name, alias, comment),
+                                    None => self
+                                        .enums
+                                        .get_mut(extends)
+                                        .unwrap()
+                                        .push_alias(name, alias, comment),
+                                }
+                            } else if let (Some(extends), Some(bitpos)) =
+                                (attr(&attributes, "extends"), attr(&attributes, "bitpos"))
+                            {
+                                let bitmask = self
+                                    .bitmasks
+                                    .get_mut(self.bitvalues.get(extends).expect("unknown FlagBits"))
+                                    .expect("unknown bitmask");
+                                bitmask.values.push(Constant {
+                                    name: name.into(),
+                                    value: bitpos.parse().unwrap(),
+                                    comment: attr(&attributes, "comment").and_then(tidy_comment),
+                                    alias: false,
+                                });
+                            } else if let Some(extends) = attr(&attributes, "extends") {
                                 const EXT_BASE: i32 = 1_000_000_000;
                                 const EXT_BLOCK_SIZE: i32 = 1000;
                                 let offset =
@@ -216,12 +289,15 @@ impl Parser {
                                 } else {
                                     1
                                 };
+                                let ext_number = attr(&attributes, "extnumber")
+                                    .map_or(ext_number, |x| x.parse().unwrap());
                                 let value =
                                     sign * (EXT_BASE + (ext_number - 1) * EXT_BLOCK_SIZE + offset);
                                 self.enums.get_mut(extends).unwrap().values.push(Constant {
                                     name: name.into(),
                                     value,
                                     comment: attr(&attributes, "comment").and_then(tidy_comment),
+                                    alias: false,
                                 });
                             } else if name.ends_with("SPEC_VERSION") {
                                 let value = attr(&attributes, "value").unwrap();
@@ -229,6 +305,18 @@ impl Parser {
                             } else if name.ends_with("EXTENSION_NAME") {
                                 let value = attr(&attributes, "value").unwrap();
                                 assert_eq!(&ext_name[..], &value[1..value.len() - 1]);
+                            } else if let Some(value) =
+                                attr(&attributes, "value").and_then(|x| x.parse().ok())
+                            {
+                                self.api_constants.push((name.into(), value));
+                            } else if let Some(alias) = attr(&attributes, "alias") {
+                                let value = self
+                                    .api_constants
+                                    .iter()
+                                    .find(|x| x.0 == alias)
+                                    .expect("unknown constant alias target")
+                                    .1;
+                                self.api_constants.push((name.into(), value));
                             } else {
                                 eprintln!("unrecognized extension constant {}", name);
                             }
@@ -261,9 +349,12 @@ impl Parser {
             self.disabled_exts.insert(ext_name.into());
         } else {
             let (tag, _) = split_ext_tag(&ext_name);
+            // Experimental tags like `MNDX` aren't declared in the registry's tag list
             self.extensions
-                .get_mut(tag)
-                .unwrap()
+                .entry(tag.into())
+                .or_insert_with(|| Tag {
+                    extensions: Vec::new(),
+                })
                 .extensions
                 .push(Extension {
                     name: ext_name,
@@ -274,6 +365,7 @@ impl Parser {
     }
 
     fn parse_commands(&mut self) {
+        let mut aliases = Vec::new();
         loop {
             use XmlEvent::*;
             match self.reader.next().expect("failed to parse XML") {
@@ -281,7 +373,16 @@ impl Parser {
                     name, attributes, ..
                 } => match &name.local_name[..] {
                     "command" => {
-                        self.parse_command(&attributes);
+                        if let Some(alias) = attr(&attributes, "alias") {
+                            // Aliases may precede the command they refer to
+                            aliases.push((
+                                attr(&attributes, "name").unwrap().to_owned(),
+                                alias.to_owned(),
+                            ));
+                            self.finish_element();
+                        } else {
+                            self.parse_command();
+                        }
                     }
                     _ => {
                         eprintln!("unimplemented commands element: {}", name.local_name);
@@ -289,6 +390,16 @@ impl Parser {
                 },
                 EndElement { name } => {
                     if name.local_name == "commands" {
+                        for (name, alias) in aliases {
+                            let params = self.commands[&alias].params.clone();
+                            self.commands.insert(
+                                name,
+                                Command {
+                                    params,
+                                    extension: None,
+                                },
+                            );
+                        }
                         break;
                     }
                     eprintln!("unexpected end element: {}", name);
@@ -301,7 +412,7 @@ impl Parser {
         }
     }
 
-    fn parse_command(&mut self, _attrs: &[OwnedAttribute]) {
+    fn parse_command(&mut self) {
         let mut command_name = None;
         let mut params = Vec::new();
         loop {
@@ -417,12 +528,21 @@ impl Parser {
                 self.parse_handle();
             }
             Some("struct") => {
-                self.parse_struct(attrs);
+                if let Some(alias) = attr(attrs, "alias") {
+                    self.struct_aliases
+                        .insert(name.unwrap().into(), alias.into());
+                    self.finish_element();
+                } else {
+                    self.parse_struct(attrs);
+                }
             }
             Some("define") => {
                 self.parse_define();
             }
-            Some("include") | Some("basetype") | Some("funcpointer") | None => {
+            Some("basetype") => {
+                self.parse_basetype();
+            }
+            Some("include") | Some("funcpointer") | None => {
                 // Intentionally skipped
                 self.finish_element();
             }
@@ -433,6 +553,49 @@ impl Parser {
         }
     }
 
+    fn parse_basetype(&mut self) {
+        let mut basetype_name = None;
+        let mut basetype_ty = None;
+        loop {
+            use XmlEvent::*;
+            match self.reader.next().expect("failed to parse XML") {
+                StartElement { name, .. } => {
+                    match &name.local_name[..] {
+                        "name" => {
+                            basetype_name = Some(self.parse_characters());
+                        }
+                        "type" => {
+                            basetype_ty = Some(self.parse_characters());
+                        }
+                        x => {
+                            panic!("unexpected element: {}", x);
+                        }
+                    }
+                    self.finish_element();
+                }
+                EndElement { name } => {
+                    if name.local_name == "type" {
+                        break;
+                    }
+                    eprintln!("unexpected end element in basetype: {}", name);
+                }
+                _ => {}
+            }
+        }
+        let name = basetype_name.unwrap();
+        // Core basetypes are hand-written
+        if !self.extensions.contains_key(split_ty_ext(&name).1) {
+            return;
+        }
+        let ty = basetype_ty.unwrap();
+        // Opaque 64-bit values are represented like atoms
+        if ty == "XR_DEFINE_ATOM" || ty == "XR_DEFINE_OPAQUE_64" {
+            self.atoms.insert(name);
+        } else {
+            self.basetypes.insert(name, ty);
+        }
+    }
+
     fn parse_define(&mut self) {
         let mut define_name = None;
         let mut define_ty = None;
@@ -455,7 +618,7 @@ impl Parser {
                     self.finish_element();
                 }
                 Characters(ch) => {
-                    if define_ty.is_some() {
+                    if define_ty.is_some() || define_name.is_some() {
                         define_val = Some(ch);
                     }
                 }
@@ -475,11 +638,19 @@ impl Parser {
                 assert!(version.ends_with(")"));
                 let version = &version[1..version.len() - 1];
                 let mut iter = version.split(", ").map(|x| x.parse::<u64>().unwrap());
-                self.api_version = Some((
-                    iter.next().unwrap() as u16,
-                    iter.next().unwrap() as u16,
-                    iter.next().unwrap() as u32,
-                ));
+                let major = iter.next().unwrap() as u16;
+                let _minor = iter.next().unwrap();
+                let patch = iter.next().unwrap() as u32;
+                // Only the 1.0 core API is targeted; see `parse_feature`
+                self.api_version = Some((major, 0, patch));
+            }
+            Some(name) if name.contains("_COUNT_") || name.starts_with("XR_MAX_") => {
+                // Array sizes introduced by extensions
+                let value = define_val.unwrap();
+                let value = value.trim().trim_end_matches('u');
+                if let Ok(value) = value.parse::<usize>() {
+                    self.api_constants.push((name.into(), value));
+                }
             }
             _ => {}
         }
@@ -519,7 +690,17 @@ impl Parser {
                 _ => {}
             }
         }
-        let parent: Option<String> = attr(attrs, "parentstruct").map(|x| x.into());
+        let parent: Option<String> = attr(attrs, "parentstruct")
+            .or_else(|| {
+                IMPLICIT_PARENTS
+                    .iter()
+                    .find(|&&(child, _)| child == struct_name)
+                    .map(|&(_, parent)| parent)
+            })
+            .map(|x| x.into());
+        let extends = attr(attrs, "structextends")
+            .map(|x| x.split(',').map(|x| x.into()).collect())
+            .unwrap_or_default();
         if let Some(ref parent) = parent {
             self.base_headers
                 .entry(parent.clone())
@@ -533,6 +714,7 @@ impl Parser {
                 ty,
                 extension: None,
                 parent,
+                extends,
                 mut_next,
             },
         );
@@ -680,6 +862,7 @@ impl Parser {
                                 name: name.into(),
                                 value: value.parse().unwrap(),
                                 comment: comment.and_then(tidy_comment),
+                                alias: false,
                             });
                         }
                         self.finish_element();
@@ -713,6 +896,7 @@ impl Parser {
                                 name: name.into(),
                                 value: bitpos.parse().unwrap(),
                                 comment: comment.and_then(tidy_comment),
+                                alias: false,
                             });
                         }
                         self.finish_element();
@@ -788,6 +972,73 @@ impl Parser {
         }
     }
 
+    /// Parse a core API version
+    ///
+    /// Only the 1.0 core API is targeted. Features of later versions are reduced to the enum
+    /// values they define, so that the extensions they were promoted from remain usable, and their
+    /// commands are dropped, as runtimes implementing only 1.0 won't provide them. The loader
+    /// negotiation interface is dropped likewise.
+    fn parse_feature(&mut self, attrs: &[OwnedAttribute]) {
+        let name = attr(attrs, "name").unwrap();
+        if name == "XR_VERSION_1_0" {
+            self.finish_element();
+            return;
+        }
+        loop {
+            use XmlEvent::*;
+            match self.reader.next().expect("failed to parse XML") {
+                StartElement {
+                    name, attributes, ..
+                } => {
+                    match &name.local_name[..] {
+                        "require" => continue,
+                        "command" => {
+                            let cmd = attr(&attributes, "name").unwrap();
+                            self.commands.shift_remove(cmd);
+                        }
+                        "enum" => {
+                            let name = attr(&attributes, "name").unwrap();
+                            if let (Some(extends), Some(ext_number), Some(offset)) = (
+                                attr(&attributes, "extends"),
+                                attr(&attributes, "extnumber"),
+                                attr(&attributes, "offset"),
+                            ) {
+                                const EXT_BASE: i32 = 1_000_000_000;
+                                const EXT_BLOCK_SIZE: i32 = 1000;
+                                let ext_number = ext_number.parse::<i32>().unwrap();
+                                let offset = offset.parse::<i32>().unwrap();
+                                let sign = if attr(&attributes, "dir").map_or(false, |x| x == "-") {
+                                    -1
+                                } else {
+                                    1
+                                };
+                                let value =
+                                    sign * (EXT_BASE + (ext_number - 1) * EXT_BLOCK_SIZE + offset);
+                                self.enums.get_mut(extends).unwrap().values.push(Constant {
+                                    name: name.into(),
+                                    value,
+                                    comment: attr(&attributes, "comment").and_then(tidy_comment),
+                                    alias: false,
+                                });
+                            }
+                        }
+                        _ => {}
+                    }
+                    self.finish_element();
+                }
+                EndElement { name } => {
+                    if name.local_name == "feature" {
+                        break;
+                    }
+                }
+                EndDocument => {
+                    panic!("unexpected end of document");
+                }
+                _ => {}
+            }
+        }
+    }
+
     fn parse_characters(&mut self) -> String {
         match self.reader.next().expect("parse error") {
             XmlEvent::Characters(x) => x,
@@ -840,7 +1091,7 @@ impl Parser {
             } else {
                 format!("See {}", self.doc_link(name))
             };
-            let debug_cases = e.values.iter().map(|v| {
+            let debug_cases = e.values.iter().filter(|v| !v.alias).map(|v| {
                 let ident = xr_enum_value_name(&name, &v.name);
                 let name = ident.to_string();
                 quote! {
@@ -848,15 +1099,16 @@ impl Parser {
                 }
             });
             let result_extras = if name == "XrResult" {
-                let cases = e.values.iter().map(|v| {
+                let cases = e.values.iter().filter(|v| !v.alias).map(|v| {
                     let ident = xr_enum_value_name(&name, &v.name);
                     let reason = v.comment.as_ref().map_or_else(
                         || ident.to_string(),
                         |x| {
                             let mut reason = x.to_string();
                             reason.get_mut(0..1).unwrap().make_ascii_lowercase();
-                            assert!(reason.ends_with("."));
-                            reason.truncate(reason.len() - 1);
+                            if reason.ends_with('.') {
+                                reason.truncate(reason.len() - 1);
+                            }
                             reason
                         },
                     );
@@ -952,6 +1204,35 @@ impl Parser {
             }
         });
 
+        let atoms = self.atoms.iter().map(|name| {
+            let ident = xr_ty_name(name);
+            let doc = format!("See {}", self.doc_link(name));
+            quote! {
+                wrapper! {
+                    #[doc = #doc]
+                    #[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+                    #ident(u64)
+                }
+            }
+        });
+
+        let basetypes = self.basetypes.iter().map(|(name, ty)| {
+            let ident = xr_ty_name(name);
+            let doc = format!("See {}", self.doc_link(name));
+            let ty = xr_var_ty(&Member {
+                name: String::new(),
+                is_const: false,
+                ptr_depth: 0,
+                ty: ty.clone(),
+                static_array_len: None,
+                len: None,
+            });
+            quote! {
+                #[doc = #doc]
+                pub type #ident = #ty;
+            }
+        });
+
         let structs = self.structs.iter().map(|(name, s)| {
             let ident = xr_ty_name(&name);
             let members = s.members.iter().map(|m| {
@@ -1018,6 +1299,18 @@ impl Parser {
             }
         });
 
+        let struct_aliases = self.struct_aliases.iter().map(|(name, alias)| {
+            let ident = xr_ty_name(name);
+            let alias = xr_ty_name(alias);
+            let doc = format!("See {}", self.doc_link(name));
+            let conditions = conditions(name);
+            quote! {
+                #[doc = #doc]
+                #conditions
+                pub type #ident = #alias;
+            }
+        });
+
         let (pfns, protos) = self
             .commands
             .iter()
@@ -1110,7 +1403,10 @@ impl Parser {
             #(#enums)*
             #(#bitmasks)*
             #(#handles)*
+            #(#atoms)*
+            #(#basetypes)*
             #(#structs)*
+            #(#struct_aliases)*
 
             /// Function pointer prototypes
             pub mod pfn {
@@ -1123,6 +1419,9 @@ impl Parser {
                     *const DebugUtilsMessengerCallbackDataEXT,
                     *mut c_void,
                 ) -> Bool32;
+                pub type EglGetProcAddressMNDX = unsafe extern "system" fn(
+                    *const c_char,
+                ) -> Option<VoidFunction>;
 
                 #(#pfns)*
             }
@@ -1160,6 +1459,10 @@ impl Parser {
         let mut ext_set_names = Vec::new();
         let mut ext_set_fields = Vec::new();
         let mut ext_set_inits = Vec::new();
+        let mut ext_set_requires = Vec::new();
+        let mut ext_set_intersections = Vec::new();
+        let mut ext_set_contains = Vec::new();
+        let mut ext_set_loaded = Vec::new();
         for (tag_name, tag) in &self.extensions {
             for ext in &tag.extensions {
                 let (pfns, pfn_inits) = ext.commands.iter().map(|cmd| {
@@ -1193,6 +1496,10 @@ impl Parser {
                 );
                 let conds = conditions(&ext.name);
                 let conds2 = conds.clone();
+                let conds8 = conds.clone();
+                let conds9 = conds.clone();
+                let conds10 = conds.clone();
+                let conds11 = conds.clone();
                 let conds3 = conds.clone();
                 let conds4 = conds.clone();
                 let conds5 = conds.clone();
@@ -1256,6 +1563,22 @@ impl Parser {
                     #conds7
                     pub #field_ident: bool,
                 });
+                ext_set_requires.push(quote! {
+                    #conds8
+                    raw::#ty_ident::NAME => { self.#field_ident = true; }
+                });
+                ext_set_contains.push(quote! {
+                    #conds10
+                    raw::#ty_ident::NAME => self.#field_ident,
+                });
+                ext_set_intersections.push(quote! {
+                    #conds9
+                    #field_ident: self.#field_ident && available.#field_ident,
+                });
+                ext_set_loaded.push(quote! {
+                    #conds11
+                    #field_ident: self.#field_ident.is_some(),
+                });
             }
         }
 
@@ -1314,7 +1637,14 @@ impl Parser {
                     }
                 }
             });
-            event_readers.push(self.generate_reader(&ident, &raw_ident, evt));
+            let doc = format!("See {}", self.doc_link(raw_name));
+            event_readers.push(self.generate_reader(
+                &simple_structs,
+                &ident,
+                &raw_ident,
+                &doc,
+                evt,
+            ));
         }
 
         let mut struct_meta = HashMap::<&str, StructMeta>::new();
@@ -1325,15 +1655,31 @@ impl Parser {
             struct_meta.insert(name, self.compute_meta(&s));
         }
 
-        let polymorphic_builders = self.base_headers.iter().filter_map(|(name, children)| {
-            if name == "XrSwapchainImageBaseHeader" || name == "XrEventDataBaseHeader" {
-                return None;
+        let polymorphic_bases = self
+            .base_headers
+            .iter()
+            .filter(|(name, _)| {
+                name != &"XrSwapchainImageBaseHeader"
+                    && name != &"XrEventDataBaseHeader"
+                    && !name.starts_with("XrBase")
+            })
+            .collect::<Vec<_>>();
+        // A pointer to a base header may refer to any of its children
+        for (name, children) in &polymorphic_bases {
+            let mut base_meta = StructMeta::default();
+            for child in children.iter() {
+                base_meta |= struct_meta[&child[..]];
             }
-            Some(self.generate_polymorphic_builders(&struct_meta, &simple_structs, name, children))
-        });
+            struct_meta.insert(name, base_meta);
+        }
 
         let whitelist = [
             "XrCompositionLayerProjectionView",
+            "XrCompositionLayerDepthInfoKHR",
+            "XrCompositionLayerSpaceWarpInfoFB",
+            "XrCompositionLayerColorScaleBiasKHR",
+            "XrCompositionLayerSettingsFB",
+            "XrCompositionLayerAlphaBlendFB",
             "XrSwapchainSubImage",
             "XrActionSetCreateInfo",
             "XrActionCreateInfo",
@@ -1341,9 +1687,24 @@ impl Parser {
         .iter()
         .cloned()
         .collect::<HashSet<&str>>();
+        let built = polymorphic_bases
+            .iter()
+            .flat_map(|(_, children)| children.iter().map(|x| &x[..]))
+            .chain(whitelist.iter().cloned())
+            .collect::<HashSet<&str>>();
+
+        let polymorphic_builders = polymorphic_bases.iter().map(|(name, children)| {
+            self.generate_polymorphic_builders(
+                &struct_meta,
+                &simple_structs,
+                &built,
+                name,
+                children,
+            )
+        });
         let builders = self.structs.iter().filter_map(|(name, s)| {
             if whitelist.contains(&name[..]) {
-                Some(self.generate_builder(&struct_meta, &simple_structs, name, s))
+                Some(self.generate_builder(&struct_meta, &simple_structs, &built, name, s))
             } else {
                 None
             }
@@ -1352,18 +1713,20 @@ impl Parser {
         quote! {
             //! Automatically generated code; do not edit!
 
-            use std::os::raw::c_char;
+            use std::{ffi::{CStr, CString}, os::raw::c_char};
             pub use sys::{#(#reexports),*};
 
             use crate::*;
 
-            /// A subset of known extensions
+            /// A set of extensions
             ///
-            /// Do not match on this exhaustively, as new fields are not considered breaking
-            /// changes.
-            #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+            /// Known extensions have a field each, and any others are listed in `other`. Do not
+            /// match on this exhaustively, as new fields are not considered breaking changes.
+            #[derive(Debug, Clone, Eq, PartialEq, Default)]
             pub struct ExtensionSet {
                 #(#ext_set_fields)*
+                /// Extensions not modeled by these bindings, e.g. vendor or beta extensions
+                pub other: Vec<CString>,
                 #[doc(hidden)]
                 pub _non_exhaustive: (),
             }
@@ -1374,20 +1737,85 @@ impl Parser {
                     for ext in properties {
                         match crate::fixed_str_bytes(&ext.extension_name) {
                             #(#ext_set_inits)*
-                            _ => {}
+                            bytes => {
+                                out.other.push(CStr::from_bytes_with_nul(bytes).unwrap().to_owned());
+                            }
                         }
                     }
                     out
                 }
 
+                /// Add the extension named `name`, which need not be known to these bindings
+                ///
+                /// Panics if `name` contains a null byte.
+                ///
+                /// # Example
+                ///
+                /// ```
+                /// let mut extensions = openxr::ExtensionSet::default();
+                /// extensions
+                ///     .require("XR_KHR_visibility_mask")
+                ///     .require("XR_VENDOR_made_up_extension");
+                /// assert!(extensions.khr_visibility_mask);
+                /// assert_eq!(extensions.other.len(), 1);
+                /// assert_eq!(extensions.other[0].to_str(), Ok("XR_VENDOR_made_up_extension"));
+                /// ```
+                pub fn require(&mut self, name: &str) -> &mut Self {
+                    let name = CString::new(name).expect("extension name contains a null byte");
+                    match name.as_bytes_with_nul() {
+                        #(#ext_set_requires)*
+                        _ => {
+                            if !self.other.contains(&name) {
+                                self.other.push(name);
+                            }
+                        }
+                    }
+                    self
+                }
+
+                /// Whether the extension named `name` is in the set
+                pub fn contains(&self, name: &str) -> bool {
+                    let name = match CString::new(name) {
+                        Ok(x) => x,
+                        Err(_) => return false,
+                    };
+                    match name.as_bytes_with_nul() {
+                        #(#ext_set_contains)*
+                        _ => self.other.contains(&name),
+                    }
+                }
+
+                /// The extensions in both `self` and `available`
+                ///
+                /// Useful to drop extensions not supported by the runtime, as reported by
+                /// `Entry::enumerate_extensions`, before creating an instance.
+                pub fn intersection(&self, available: &ExtensionSet) -> ExtensionSet {
+                    ExtensionSet {
+                        #(#ext_set_intersections)*
+                        other: self
+                            .other
+                            .iter()
+                            .filter(|x| available.other.contains(x))
+                            .cloned()
+                            .collect(),
+                        _non_exhaustive: (),
+                    }
+                }
+
                 pub(crate) fn names(&self) -> Vec<*const c_char> {
                     let mut out = Vec::new();
                     #(#ext_set_names)*
+                    for name in &self.other {
+                        out.push(name.as_ptr());
+                    }
                     out
                 }
             }
 
-            /// Extensions used internally by the bindings
+            /// Function pointers for each extension, loaded when the instance was created
+            ///
+            /// Each field is `None` if the corresponding extension was not enabled. These may be
+            /// used to call extension entry points not yet wrapped by the high-level bindings.
             #[derive(Default, Copy, Clone)]
             pub struct InstanceExtensions {
                 #(#ext_fields)*
@@ -1404,27 +1832,40 @@ impl Parser {
                         #(#ext_field_inits)*
                     })
                 }
+
+                /// The set of extensions which were loaded
+                ///
+                /// Extensions unknown to these bindings are not tracked here, so `other` is always
+                /// empty.
+                pub fn enabled(&self) -> ExtensionSet {
+                    ExtensionSet {
+                        #(#ext_set_loaded)*
+                        other: Vec::new(),
+                        _non_exhaustive: (),
+                    }
+                }
             }
 
             #[derive(Copy, Clone)]
             pub enum Event<'a> {
                 #(#event_cases,)*
+                /// An event of a type not recognized by these bindings, e.g. from an unknown
+                /// extension
+                Other(StructureType),
             }
 
             impl<'a> Event<'a> {
                 /// Decode an event
                 ///
-                /// Returns `None` if the event type is not recognized, e.g. if it's from an unknown extension
-                ///
                 /// # Safety
                 ///
                 /// `raw` must refer to an `EventDataBuffer` populated by a successful call to
                 /// `xrPollEvent`, which has not been moved since.
-                pub unsafe fn from_raw(raw: *const sys::EventDataBuffer) -> Option<Self> {
-                    Some(match (raw as *const sys::BaseInStructure).read().ty {
+                pub unsafe fn from_raw(raw: *const sys::EventDataBuffer) -> Self {
+                    match (raw as *const sys::BaseInStructure).read().ty {
                         #(#event_decodes)*
-                        _ => { return None; }
-                    })
+                        ty => Event::Other(ty),
+                    }
                 }
             }
 
@@ -1458,7 +1899,7 @@ impl Parser {
 
             #[allow(unused)]
             pub(crate) mod builder {
-                use std::{mem, marker::PhantomData, ops::Deref};
+                use std::{mem, marker::PhantomData, ops::Deref, os::raw::c_void};
 
                 use crate::*;
 
@@ -1484,33 +1925,37 @@ impl Parser {
         &self,
         meta: &HashMap<&str, StructMeta>,
         simple: &IndexSet<&str>,
+        built: &HashSet<&str>,
         base_name: &str,
         children: &[String],
     ) -> TokenStream {
         let sys_ident = xr_ty_name(base_name);
-        assert!(base_name.starts_with("Xr") && base_name.ends_with("BaseHeader"));
+        let (stem, ext) = split_ty_ext(base_name);
+        assert!(stem.starts_with("Xr") && stem.ends_with("BaseHeader"));
         let base_ident = Ident::new(
-            &base_name[2..base_name.len() - "Header".len()],
+            &format!("{}{}", &stem[2..stem.len() - "Header".len()], ext),
             Span::call_site(),
         );
-        let mut base_meta = StructMeta::default();
-        for name in children {
-            base_meta |= *meta.get(&name[..]).unwrap();
-        }
-
-        let (type_params, type_args, marker, marker_init) = base_meta.type_params();
+        let (type_params, type_args, marker, marker_init) = meta[base_name].type_params();
         let builders = children.iter().map(|name| {
             let ident = xr_ty_name(name);
             let s = self.structs.get(name).unwrap();
             let inits = self.generate_builder_inits(s);
-            let setters = self.generate_setters(meta, simple, s);
+            let setters = self.generate_setters(meta, simple, built, name, s);
+            let next_setters = self.generate_next_setters(meta, built, name);
+            let conds = conditions(name);
+            let conds2 = conds.clone();
+            let conds3 = conds.clone();
+            let conds4 = conds.clone();
             quote! {
                 #[derive(Copy, Clone)]
                 #[repr(transparent)]
+                #conds
                 pub struct #ident #type_params {
                     inner: sys::#ident,
                     #marker
                 }
+                #conds2
                 impl #type_params #ident #type_args {
                     #[inline]
                     pub fn new() -> Self {
@@ -1548,7 +1993,15 @@ impl Parser {
                     }
 
                     #setters
+                    #next_setters
+                }
+                #conds3
+                impl #type_params Default for #ident #type_args {
+                    fn default() -> Self {
+                        Self::new()
+                    }
                 }
+                #conds4
                 impl #type_params Deref for #ident #type_args {
                     type Target = #base_ident #type_args;
 
@@ -1574,6 +2027,8 @@ impl Parser {
         &self,
         meta: &HashMap<&str, StructMeta>,
         simple: &IndexSet<&str>,
+        built: &HashSet<&str>,
+        name: &str,
         s: &Struct,
     ) -> TokenStream {
         let lens = s
@@ -1596,17 +2051,68 @@ impl Parser {
             } else {
                 quote! {}
             };
+            let mut generics = quote! {};
             let (ty, init) = match &m.ty[..] {
                 "XrBool32" => (
                     quote! { bool },
                     quote! { self.inner.#ident = value.into(); },
                 ),
-                x if self.handles.contains(x) => {
-                    assert!(m.len.is_none());
+                "XrAction" => {
+                    assert!(m.len.is_none() && m.ptr_depth == 0);
+                    generics = quote! { <T: ActionTy> };
+                    (
+                        quote! { &'a Action<T> },
+                        quote! { self.inner.#ident = value.as_raw(); },
+                    )
+                }
+                x if HL_HANDLES.contains(&x) => {
+                    assert!(m.len.is_none() && m.ptr_depth == 0);
                     let ty = xr_var_ty(&m);
+                    let check = if x == "XrSwapchain" && name == "XrCompositionLayerCubeKHR" {
+                        quote! {
+                            debug_assert!(
+                                value.face_count().unwrap_or(6) == 6,
+                                "cube layers require a swapchain with 6 faces"
+                            );
+                        }
+                    } else if x == "XrSwapchain" && name == "XrSwapchainSubImage" {
+                        // Default to the whole image, as an invalid rect fails the entire frame
+                        quote! {
+                            if self.inner.image_rect.extent.width == 0
+                                && self.inner.image_rect.extent.height == 0
+                            {
+                                if let Some(rect) = value.full_rect() {
+                                    self.inner.image_rect = rect;
+                                }
+                            }
+                        }
+                    } else {
+                        quote! {}
+                    };
                     (
                         quote! { &'a #ty #type_args },
-                        quote! { self.inner.#ident = value.as_raw(); },
+                        quote! {
+                            #check
+                            self.inner.#ident = value.as_raw();
+                        },
+                    )
+                }
+                x if self.handles.contains(x) => {
+                    assert!(m.len.is_none());
+                    (
+                        self.hl_var_ty(simple, &m),
+                        quote! { self.inner.#ident = value; },
+                    )
+                }
+                x if self.base_headers.contains_key(x) && m.ptr_depth == 1 && m.len.is_none() => {
+                    let (stem, ext) = split_ty_ext(x);
+                    let base_ident = Ident::new(
+                        &format!("{}{}", &stem[2..stem.len() - "Header".len()], ext),
+                        Span::call_site(),
+                    );
+                    (
+                        quote! { &'a #base_ident #type_args },
+                        quote! { self.inner.#ident = value as *const _ as _; },
                     )
                 }
                 _ => {
@@ -1622,7 +2128,11 @@ impl Parser {
                         let mut inner = m.clone();
                         inner.ptr_depth -= 1;
                         assert_eq!(inner.ptr_depth, 0, "nested arrays are unimplemented");
-                        let ty = xr_var_ty(&inner);
+                        let ty = if built.contains(&m.ty[..]) {
+                            xr_var_ty(&inner)
+                        } else {
+                            self.hl_var_ty(simple, &inner)
+                        };
                         let len = xr_var_name(&len[0]);
                         (
                             quote! { &'a [#ty #type_args] },
@@ -1634,25 +2144,35 @@ impl Parser {
                     } else if m.ptr_depth != 0 {
                         let mut inner = m.clone();
                         inner.ptr_depth -= 1;
-                        let ty = xr_var_ty(&inner);
+                        let ty = if built.contains(&m.ty[..]) {
+                            xr_var_ty(&inner)
+                        } else {
+                            self.hl_var_ty(simple, &inner)
+                        };
                         (
                             quote! { &'a #ty #type_args },
                             quote! { self.inner.#ident = value as *const _ as _; },
                         )
-                    } else if self.structs.contains_key(&m.ty) && !simple.contains(&m.ty[..]) {
+                    } else if self.structs.contains_key(&m.ty)
+                        && !simple.contains(&m.ty[..])
+                        && built.contains(&m.ty[..])
+                    {
                         let ty = xr_var_ty(&m);
                         (
                             quote! { #ty #type_args },
                             quote! { self.inner.#ident = value.inner; },
                         )
                     } else {
-                        (xr_var_ty(&m), quote! { self.inner.#ident = value; })
+                        (
+                            self.hl_var_ty(simple, &m),
+                            quote! { self.inner.#ident = value; },
+                        )
                     }
                 }
             };
             Some(quote! {
                 #[inline]
-                pub fn #ident(mut self, value: #ty) -> Self {
+                pub fn #ident #generics (mut self, value: #ty) -> Self {
                     #init
                     self
                 }
@@ -1663,6 +2183,72 @@ impl Parser {
         }
     }
 
+    /// Setters for chaining built structs that extend `name`, or the base header it's a child of,
+    /// onto its `next` pointer
+    ///
+    /// If `name` itself extends other structs, built structs extending the same ones may also be
+    /// chained onto it, so that several can be chained at once.
+    fn generate_next_setters(
+        &self,
+        meta: &HashMap<&str, StructMeta>,
+        built: &HashSet<&str>,
+        name: &str,
+    ) -> TokenStream {
+        let parent = self.structs[name].parent.as_ref();
+        let extends = &self.structs[name].extends;
+        let (stem, _) = split_ty_ext(name);
+        let stem_words = stem.to_snake_case();
+        let stem_words = stem_words.split('_').collect::<Vec<_>>();
+        let setters = self
+            .structs
+            .iter()
+            .filter(|(child, s)| {
+                // Children of a base header are used in place of it, not chained onto it
+                built.contains(&child[..])
+                    && &child[..] != name
+                    && s.parent.is_none()
+                    && s.extends
+                        .iter()
+                        .any(|x| x == name || Some(x) == parent || extends.contains(x))
+            })
+            .map(|(child, s)| {
+                let child_ident = xr_ty_name(child);
+                let (_, child_type_args, _, _) = meta[&child[..]].type_params();
+                // Drop the words shared with the extended struct, e.g. `CompositionLayer`
+                let words = child[2..].to_snake_case();
+                let words = words.split('_').collect::<Vec<_>>();
+                let shared = words
+                    .iter()
+                    .zip(stem_words.iter().skip(1))
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                let ident = Ident::new(&words[shared..].join("_"), Span::call_site());
+                let direct = s.extends.iter().any(|x| x == name || Some(x) == parent);
+                let doc = if direct {
+                    format!(
+                        "Chain a `{}` onto this structure, replacing anything chained previously",
+                        child_ident
+                    )
+                } else {
+                    format!(
+                        "Chain a `{}` after this structure, replacing anything chained previously",
+                        child_ident
+                    )
+                };
+                quote! {
+                    #[doc = #doc]
+                    #[inline]
+                    pub fn #ident(mut self, value: &'a #child_ident #child_type_args) -> Self {
+                        self.inner.next = value as *const _ as _;
+                        self
+                    }
+                }
+            });
+        quote! {
+            #(#setters)*
+        }
+    }
+
     fn generate_builder_inits(&self, s: &Struct) -> TokenStream {
         let inits = s.members.iter().filter_map(|m| {
             let ident = xr_var_name(&m.name);
@@ -1681,15 +2267,18 @@ impl Parser {
         &self,
         meta: &HashMap<&str, StructMeta>,
         simple: &IndexSet<&str>,
+        built: &HashSet<&str>,
         name: &str,
         s: &Struct,
     ) -> TokenStream {
-        let setters = self.generate_setters(meta, simple, s);
+        let setters = self.generate_setters(meta, simple, built, name, s);
+        let next_setters = self.generate_next_setters(meta, built, name);
         let ident = xr_ty_name(name);
         let (type_params, type_args, marker, marker_init) = meta.get(name).unwrap().type_params();
         let inits = self.generate_builder_inits(s);
         let conds = conditions(&name);
         let conds2 = conds.clone();
+        let conds3 = conds.clone();
         quote! {
             #[derive(Copy, Clone)]
             #[repr(transparent)]
@@ -1736,11 +2325,25 @@ impl Parser {
                 }
 
                 #setters
+                #next_setters
+            }
+            #conds3
+            impl #type_params Default for #ident #type_args {
+                fn default() -> Self {
+                    Self::new()
+                }
             }
         }
     }
 
-    fn generate_reader(&self, ident: &Ident, raw_ident: &Ident, s: &Struct) -> TokenStream {
+    fn generate_reader(
+        &self,
+        simple: &IndexSet<&str>,
+        ident: &Ident,
+        raw_ident: &Ident,
+        doc: &str,
+        s: &Struct,
+    ) -> TokenStream {
         let lens = s
             .members
             .iter()
@@ -1755,11 +2358,8 @@ impl Parser {
             let ident = xr_var_name(&m.name);
             let (ty, value) = if m.ty == "XrBool32" {
                 (quote! { bool }, quote! { (self.0).#ident.into() })
-            } else if self.handles.contains(&m.ty) {
-                let ty = xr_var_ty(&m);
-                (quote! { sys::#ty }, quote! { (self.0).#ident })
             } else {
-                (xr_var_ty(&m), quote! { (self.0).#ident })
+                (self.hl_var_ty(simple, &m), quote! { (self.0).#ident })
             };
             Some(quote! {
                 #[inline]
@@ -1770,6 +2370,7 @@ impl Parser {
         });
 
         quote! {
+            #[doc = #doc]
             #[derive(Copy, Clone)]
             pub struct #ident<'a>(&'a sys::#raw_ident);
 
@@ -1784,6 +2385,31 @@ impl Parser {
         }
     }
 
+    /// Like `xr_var_ty`, but with paths valid in the high-level API, which only reexports some
+    /// types from `sys`
+    fn hl_var_ty(&self, simple: &IndexSet<&str>, member: &Member) -> TokenStream {
+        let ty = &member.ty[..];
+        let base = xr_base_ty(member);
+        let base = if ty.starts_with("Xr") {
+            let canonical = self.struct_aliases.get(ty).map_or(ty, |x| &x[..]);
+            let qualify = ty == "XrResult"
+                || self.handles.contains(ty)
+                || self.atoms.contains(ty)
+                || self.basetypes.contains_key(ty)
+                || (self.structs.contains_key(canonical) && !simple.contains(canonical));
+            if qualify {
+                quote! { sys::#base }
+            } else {
+                base
+            }
+        } else if ty.starts_with("PFN_") || PRIMITIVE_TYPES.contains(&ty) {
+            base
+        } else {
+            quote! { sys::platform::#base }
+        };
+        wrap_var_ty(member, base, quote! { sys:: })
+    }
+
     /// Determine whether a struct is simple enough to be reexported directly from the high-level API
     fn is_simple_struct(&self, s: &Struct) -> bool {
         s.members.iter().all(|x| {
@@ -1878,10 +2504,30 @@ impl<T> Enum<T> {
     }
 }
 
+impl<T: Copy> Enum<T> {
+    /// Add an alternate name for an existing value
+    fn push_alias(&mut self, name: &str, alias: &str, comment: Option<String>) {
+        let value = self
+            .values
+            .iter()
+            .find(|x| x.name == alias)
+            .expect("unknown enum alias target")
+            .value;
+        self.values.push(Constant {
+            name: name.into(),
+            value,
+            comment,
+            alias: true,
+        });
+    }
+}
+
 struct Constant<T> {
     name: String,
     value: T,
     comment: Option<String>,
+    /// Whether this is an alternate name for another constant of the same type
+    alias: bool,
 }
 
 struct Struct {
@@ -1889,6 +2535,8 @@ struct Struct {
     extension: Option<Rc<str>>,
     ty: Option<String>,
     parent: Option<String>,
+    /// Structs this may be chained onto via their `next` pointer
+    extends: Vec<String>,
     mut_next: bool,
 }
 
@@ -1925,7 +2573,10 @@ fn xr_ty_name(raw: &str) -> Ident {
 }
 
 fn split_ty_ext(name: &str) -> (&str, &str) {
-    let ext = name.rfind(|x: char| x.is_lowercase()).unwrap() + 1;
+    let ext = name
+        .rfind(|x: char| x.is_lowercase() || x.is_ascii_digit())
+        .unwrap()
+        + 1;
     name.split_at(ext)
 }
 
@@ -1942,7 +2593,13 @@ fn xr_enum_value_name(ty: &str, name: &str) -> Ident {
     } else {
         name.len()
     };
-    Ident::new(&name[prefix_len..end], Span::call_site())
+    let start = if name[prefix_len..].starts_with(|x: char| x.is_ascii_digit()) {
+        // Keep the last word of the prefix to form a valid identifier, e.g. `DICT_4X4_50`
+        name[..prefix_len - 1].rfind('_').unwrap() + 1
+    } else {
+        prefix_len
+    };
+    Ident::new(&name[start..end], Span::call_site())
 }
 
 fn xr_bitmask_value_name(ty: &str, name: &str) -> Ident {
@@ -1955,9 +2612,22 @@ fn xr_bitmask_value_name(ty: &str, name: &str) -> Ident {
     } else {
         name.len()
     };
-    assert!(name[..end].ends_with("_BIT"));
-    let end = end - "_BIT".len();
-    Ident::new(&name[prefix_len..end], Span::call_site())
+    if end == prefix_len + "BIT".len() {
+        // The value is named after the type itself, e.g. `XR_PASSTHROUGH_CAPABILITY_BIT_FB`
+        return Ident::new(&name["XR_".len()..end - "_BIT".len()], Span::call_site());
+    }
+    let name = &name[prefix_len..end];
+    if name.ends_with("_BIT") {
+        return Ident::new(&name[..name.len() - "_BIT".len()], Span::call_site());
+    }
+    // Extension-defined bits of core types retain their vendor suffix
+    let bit = name
+        .rfind("_BIT_")
+        .unwrap_or_else(|| panic!("bitmask value name lacks _BIT: {}", name));
+    Ident::new(
+        &format!("{}{}", &name[..bit], &name[bit + "_BIT".len()..]),
+        Span::call_site(),
+    )
 }
 
 fn xr_var_name(raw: &str) -> Ident {
@@ -1980,9 +2650,48 @@ fn xr_arg_ty(member: &Member) -> TokenStream {
 }
 
 fn xr_var_ty(member: &Member) -> TokenStream {
-    let ty = if member.ty.starts_with("Xr") {
+    wrap_var_ty(member, xr_base_ty(member), quote! {})
+}
+
+/// Types used only by the loader negotiation interface
+const LOADER_TYPES: &[&str] = &[
+    "XrLoaderInterfaceStructs",
+    "XrNegotiateLoaderInfo",
+    "XrNegotiateApiLayerRequest",
+    "XrNegotiateRuntimeRequest",
+    "XrApiLayerNextInfo",
+    "XrApiLayerCreateInfo",
+];
+
+/// Structs usable in place of a base header which the registry doesn't declare a `parentstruct` for
+const IMPLICIT_PARENTS: &[(&str, &str)] = &[(
+    "XrCompositionLayerPassthroughFB",
+    "XrCompositionLayerBaseHeader",
+)];
+
+/// Handles with wrappers in the high-level API that builders accept directly
+const HL_HANDLES: &[&str] = &[
+    "XrInstance",
+    "XrSession",
+    "XrSwapchain",
+    "XrSpace",
+    "XrActionSet",
+];
+
+/// C types that map directly onto Rust primitives
+const PRIMITIVE_TYPES: &[&str] = &[
+    "uint64_t", "uint32_t", "uint16_t", "uint8_t", "char", "int64_t", "int32_t", "int16_t",
+    "int8_t", "float", "double", "void",
+];
+
+/// The type of a single element of `member`, ignoring arrays and pointers
+fn xr_base_ty(member: &Member) -> TokenStream {
+    if member.ty.starts_with("Xr") {
         let ident = xr_ty_name(&member.ty);
         quote! { #ident }
+    } else if member.ty.starts_with("PFN_vk") {
+        let ident = Ident::new(&member.ty, Span::call_site());
+        quote! { Option<#ident> }
     } else if member.ty.starts_with("PFN_") {
         let ident = xr_command_name(&member.ty["PFN_".len()..]);
         quote! { Option<pfn::#ident> }
@@ -2004,14 +2713,19 @@ fn xr_var_ty(member: &Member) -> TokenStream {
         };
         let ident = Ident::new(&ty, Span::call_site());
         quote! { #ident }
-    };
+    }
+}
+
+/// Wrap the element type `ty` of `member` in its arrays and pointers, qualifying named array
+/// lengths with `len_path`
+fn wrap_var_ty(member: &Member, ty: TokenStream, len_path: TokenStream) -> TokenStream {
     let mut ty = if let Some(ref len) = member.static_array_len {
         if let Ok(len) = len.parse::<usize>() {
             quote! { [#ty; #len] }
         } else {
-            assert!(len.starts_with("XR_MAX_"));
+            assert!(len.starts_with("XR_"));
             let len = Ident::new(&len[3..], Span::call_site());
-            quote! { [#ty; #len] }
+            quote! { [#ty; #len_path #len] }
         }
     } else {
         quote! { #ty }
@@ -0,0 +1,30 @@
+//! Interop between the `openxr` crate and `wgpu-hal`
+//!
+//! Lives in its own crate because `wgpu-hal` requires a much newer Rust than `openxr`.
+
+use ash::vk::Handle;
+use openxr::vulkan;
+
+/// Extension methods for `vulkan::SessionCreateInfo`
+pub trait SessionCreateInfoExt {
+    /// Use the device and queue of a `wgpu-hal` Vulkan device
+    ///
+    /// The device must have been created on the physical device identified by
+    /// `vulkan::get_physical_device`, with the Vulkan instance and device extensions required by
+    /// the runtime, and must outlive any session created with the result. Each `VkImage` returned
+    /// by `Swapchain::enumerate_images` may then be wrapped as a `wgpu-hal` texture with
+    /// `Device::texture_from_raw(ash::vk::Image::from_raw(image), ..)`.
+    fn from_wgpu_hal(device: &wgpu_hal::vulkan::Device) -> Self;
+}
+
+impl SessionCreateInfoExt for vulkan::SessionCreateInfo {
+    fn from_wgpu_hal(device: &wgpu_hal::vulkan::Device) -> Self {
+        Self {
+            instance: device.shared_instance().raw_instance().handle().as_raw() as usize as _,
+            physical_device: device.raw_physical_device().as_raw() as usize as _,
+            device: device.raw_device().handle().as_raw() as usize as _,
+            queue_family_index: device.queue_family_index(),
+            queue_index: device.queue_index(),
+        }
+    }
+}
@@ -257,6 +257,16 @@ mod inner {
                     EventsLost(e) => {
                         println!("lost {} events", e.lost_event_count());
                     }
+                    ReferenceSpaceChangePending(e) => {
+                        // Content anchored to the old origin can be moved by
+                        // `pose_in_previous_space` at `change_time` to stay in place
+                        println!(
+                            "{:?} space origin changing at {:?} (pose known: {})",
+                            e.reference_space_type(),
+                            e.change_time(),
+                            e.pose_valid()
+                        );
+                    }
                     _ => {
                         println!("unhandled event");
                     }
@@ -36,20 +36,39 @@ impl<T: ActionTy> Action<T> {
     /// Set the debug name of this `Action`, if `XR_EXT_debug_utils` is loaded
     #[inline]
     pub fn set_name(&mut self, name: &str) -> Result<()> {
-        self.instance().set_name_raw(self.as_raw().into_raw(), name)
+        self.instance()
+            .set_name_raw(ObjectType::ACTION, self.as_raw().into_raw(), name)
     }
 
     /// Input sources currently bound to this action
+    ///
+    /// Empty if the action is not bound to anything. Pass the results to
+    /// `Session::input_source_localized_name` to describe them to the user.
     #[inline]
-    pub fn bound_sources<G: Graphics>(&self, session: &Session<G>) -> Result<Vec<Path>> {
+    pub fn enumerate_bound_sources<G: Graphics>(&self, session: &Session<G>) -> Result<Vec<Path>> {
         let info = sys::BoundSourcesForActionEnumerateInfo {
             ty: sys::BoundSourcesForActionEnumerateInfo::TYPE,
             next: ptr::null(),
             action: self.as_raw(),
         };
-        get_arr(|cap, count, buf| unsafe {
-            (self.fp().enumerate_bound_sources_for_action)(session.as_raw(), &info, cap, count, buf)
-        })
+        get_arr_trusted(
+            "xrEnumerateBoundSourcesForAction",
+            |cap, count, buf| unsafe {
+                (self.fp().enumerate_bound_sources_for_action)(
+                    session.as_raw(),
+                    &info,
+                    cap,
+                    count,
+                    buf,
+                )
+            },
+        )
+    }
+
+    #[deprecated(note = "use enumerate_bound_sources")]
+    #[inline]
+    pub fn bound_sources<G: Graphics>(&self, session: &Session<G>) -> Result<Vec<Path>> {
+        self.enumerate_bound_sources(session)
     }
 
     // Private helper
@@ -70,6 +89,8 @@ impl<T: ActionTy> Clone for Action<T> {
 
 impl<T: ActionInput> Action<T> {
     /// Retrieve the current state
+    ///
+    /// Reflects the state as of the most recent call to `Session::sync_actions`.
     pub fn state<G: Graphics>(
         &self,
         session: &Session<G>,
@@ -77,13 +98,30 @@ impl<T: ActionInput> Action<T> {
     ) -> Result<ActionState<T>> {
         T::get(self, session, subaction_path)
     }
+
+    /// Retrieve the current state for each of several subaction paths, e.g. one per hand
+    ///
+    /// Equivalent to calling `state` for each element of `subaction_paths` in order.
+    pub fn states<G: Graphics>(
+        &self,
+        session: &Session<G>,
+        subaction_paths: &[Path],
+    ) -> Result<Vec<ActionState<T>>> {
+        subaction_paths
+            .iter()
+            .map(|&path| self.state(session, path))
+            .collect()
+    }
 }
 
 impl Action<Posef> {
     /// Creates a `Space` relative to this action
+    ///
+    /// The space tracks the input source bound to this action for `subaction_path`, e.g. a
+    /// controller's `/input/grip/pose`, and keeps both the action and `session` alive.
     pub fn create_space<G: Graphics>(
         &self,
-        session: Session<G>,
+        session: &Session<G>,
         subaction_path: Path,
         pose_in_action_space: Posef,
     ) -> Result<Space> {
@@ -96,15 +134,18 @@ impl Action<Posef> {
         };
         let mut out = sys::Space::NULL;
         unsafe {
-            cvt((self.fp().create_action_space)(
-                session.as_raw(),
-                &info,
-                &mut out,
-            ))?;
-            Ok(Space::action_from_raw(self.clone(), session, out))
+            cvt(
+                "xrCreateActionSpace",
+                (self.fp().create_action_space)(session.as_raw(), &info, &mut out),
+            )?;
+            Ok(Space::action_from_raw(self.clone(), session.clone(), out))
         }
     }
 
+    /// Whether any input source is currently bound to and providing data for this action
+    ///
+    /// Pose actions carry no value of their own; locate a `Space` created with `create_space` to
+    /// obtain the pose itself.
     pub fn is_active<G: Graphics>(
         &self,
         session: &Session<G>,
@@ -118,11 +159,10 @@ impl Action<Posef> {
         };
         let out = unsafe {
             let mut out = sys::ActionStatePose::out(ptr::null_mut());
-            cvt((self.fp().get_action_state_pose)(
-                session.as_raw(),
-                &info,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetActionStatePose",
+                (self.fp().get_action_state_pose)(session.as_raw(), &info, out.as_mut_ptr()),
+            )?;
             out.assume_init()
         };
         Ok(out.is_active.into())
@@ -130,6 +170,10 @@ impl Action<Posef> {
 }
 
 impl Action<Haptic> {
+    /// Send a haptic event, e.g. a `HapticVibration`, to the devices bound to this action
+    ///
+    /// Use `Duration::MIN_HAPTIC` for the shortest pulse the runtime supports, and
+    /// `FREQUENCY_UNSPECIFIED` to let the runtime choose an optimal frequency.
     pub fn apply_feedback<G: Graphics>(
         &self,
         session: &Session<G>,
@@ -143,15 +187,15 @@ impl Action<Haptic> {
             subaction_path,
         };
         unsafe {
-            cvt((self.fp().apply_haptic_feedback)(
-                session.as_raw(),
-                &info,
-                event as *const _ as _,
-            ))?;
+            cvt(
+                "xrApplyHapticFeedback",
+                (self.fp().apply_haptic_feedback)(session.as_raw(), &info, event as *const _ as _),
+            )?;
         }
         Ok(())
     }
 
+    /// Stop any haptic feedback currently playing on the devices bound to this action
     pub fn stop_feedback<G: Graphics>(
         &self,
         session: &Session<G>,
@@ -164,25 +208,34 @@ impl Action<Haptic> {
             subaction_path,
         };
         unsafe {
-            cvt((self.fp().stop_haptic_feedback)(session.as_raw(), &info))?;
+            cvt(
+                "xrStopHapticFeedback",
+                (self.fp().stop_haptic_feedback)(session.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
 }
 
+/// Types that can be the value of an action, selecting its `ActionType`
 pub trait ActionTy: Sized {
     #[doc(hidden)]
     const TYPE: ActionType;
 }
 
+/// The state of an input action as of the most recent `Session::sync_actions`
 #[derive(Debug, Copy, Clone)]
 pub struct ActionState<T: ActionInput> {
     pub current_state: T,
+    /// Whether `current_state` differs from its value before the most recent sync
     pub changed_since_last_sync: bool,
+    /// When `current_state` last changed, if `changed_since_last_sync` is set
     pub last_change_time: Time,
+    /// Whether any input source is currently bound to and providing data for this action
     pub is_active: bool,
 }
 
+/// Action value types whose state can be queried with `Action::state`
 pub trait ActionInput: ActionTy {
     #[doc(hidden)]
     fn get<G: Graphics>(
@@ -210,11 +263,10 @@ impl ActionInput for bool {
         };
         unsafe {
             let mut out = sys::ActionStateBoolean::out(ptr::null_mut());
-            cvt((action.fp().get_action_state_boolean)(
-                session.as_raw(),
-                &info,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetActionStateBoolean",
+                (action.fp().get_action_state_boolean)(session.as_raw(), &info, out.as_mut_ptr()),
+            )?;
             let out = out.assume_init();
             Ok(ActionState {
                 current_state: out.current_state.into(),
@@ -244,11 +296,10 @@ impl ActionInput for f32 {
         };
         unsafe {
             let mut out = sys::ActionStateFloat::out(ptr::null_mut());
-            cvt((action.fp().get_action_state_float)(
-                session.as_raw(),
-                &info,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetActionStateFloat",
+                (action.fp().get_action_state_float)(session.as_raw(), &info, out.as_mut_ptr()),
+            )?;
             let out = out.assume_init();
             Ok(ActionState {
                 current_state: out.current_state,
@@ -278,11 +329,10 @@ impl ActionInput for Vector2f {
         };
         unsafe {
             let mut out = sys::ActionStateVector2f::out(ptr::null_mut());
-            cvt((action.fp().get_action_state_vector2f)(
-                session.as_raw(),
-                &info,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetActionStateVector2f",
+                (action.fp().get_action_state_vector2f)(session.as_raw(), &info, out.as_mut_ptr()),
+            )?;
             let out = out.assume_init();
             Ok(ActionState {
                 current_state: out.current_state,
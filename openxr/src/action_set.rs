@@ -35,10 +35,15 @@ impl ActionSet {
     /// Set the debug name of this `ActionSet`, if `XR_EXT_debug_utils` is loaded
     #[inline]
     pub fn set_name(&mut self, name: &str) -> Result<()> {
-        self.instance().set_name_raw(self.as_raw().into_raw(), name)
+        self.instance()
+            .set_name_raw(ObjectType::ACTION_SET, self.as_raw().into_raw(), name)
     }
 
     /// Create a new logical input action
+    ///
+    /// The action's `ActionType` is selected by `T`. Returns `ERROR_NAME_INVALID` or
+    /// `ERROR_LOCALIZED_NAME_INVALID` if `name` or `localized_name` respectively is empty or too
+    /// long.
     #[inline]
     pub fn create_action<T: ActionTy>(
         &self,
@@ -46,6 +51,13 @@ impl ActionSet {
         localized_name: &str,
         subaction_paths: &[Path],
     ) -> Result<Action<T>> {
+        if name.is_empty() || name.len() >= sys::MAX_ACTION_NAME_SIZE {
+            return Err(error(None, sys::Result::ERROR_NAME_INVALID));
+        }
+        if localized_name.is_empty() || localized_name.len() >= sys::MAX_LOCALIZED_ACTION_NAME_SIZE
+        {
+            return Err(error(None, sys::Result::ERROR_LOCALIZED_NAME_INVALID));
+        }
         let info = builder::ActionCreateInfo::new()
             .action_name(name)
             .localized_action_name(localized_name)
@@ -53,11 +65,10 @@ impl ActionSet {
             .action_type(T::TYPE);
         unsafe {
             let mut out = sys::Action::NULL;
-            cvt((self.fp().create_action)(
-                self.as_raw(),
-                info.as_raw(),
-                &mut out,
-            ))?;
+            cvt(
+                "xrCreateAction",
+                (self.fp().create_action)(self.as_raw(), info.as_raw(), &mut out),
+            )?;
             Ok(Action::from_raw(self.clone(), out))
         }
     }
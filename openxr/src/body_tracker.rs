@@ -0,0 +1,145 @@
+use std::{ptr, sync::Arc};
+
+use crate::*;
+
+/// Number of joints reported by `BodyTracker::locate`, i.e. `BodyJointFB::COUNT`
+pub const BODY_JOINT_COUNT: usize = 70;
+
+/// Tracks the joints of the user's upper body
+///
+/// Created by `Session::create_body_tracker`. Requires `XR_FB_body_tracking`.
+pub struct BodyTracker {
+    session: Arc<session::SessionInner>,
+    handle: sys::BodyTrackerFB,
+}
+
+impl BodyTracker {
+    /// Take ownership of an existing body tracker handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid body tracker handle associated with `session`, which must have
+    /// been created with `XR_FB_body_tracking` enabled and `BodyJointSetFB::DEFAULT`.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(session: Session<G>, handle: sys::BodyTrackerFB) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+        }
+    }
+
+    /// Access the raw body tracker handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::BodyTrackerFB {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// Determine the location of each joint of the body relative to `base` at `time`
+    ///
+    /// Poses are only meaningful when the corresponding bits of each joint's flags are set.
+    #[inline]
+    pub fn locate(&self, base: &Space, time: Time) -> Result<BodyJointLocations> {
+        // This assert allows this function to be safe.
+        assert_eq!(&*self.session as *const session::SessionInner, &*base.session as *const session::SessionInner,
+                   "`self` and `base` must have been created, allocated, or retrieved from the same `Session`");
+        let info = sys::BodyJointsLocateInfoFB {
+            ty: sys::BodyJointsLocateInfoFB::TYPE,
+            next: ptr::null(),
+            base_space: base.as_raw(),
+            time,
+        };
+        // Joint array is written in full by the runtime on success
+        let mut locations = BodyJointLocations {
+            is_active: false,
+            confidence: 0.0,
+            skeleton_changed_count: 0,
+            time,
+            joints: [sys::BodyJointLocationFB {
+                location_flags: SpaceLocationFlags::EMPTY,
+                pose: Posef::IDENTITY,
+            }; BODY_JOINT_COUNT],
+        };
+        let mut raw = sys::BodyJointLocationsFB {
+            ty: sys::BodyJointLocationsFB::TYPE,
+            next: ptr::null_mut(),
+            is_active: false.into(),
+            confidence: 0.0,
+            joint_count: BODY_JOINT_COUNT as u32,
+            joint_locations: locations.joints.as_mut_ptr(),
+            skeleton_changed_count: 0,
+            time,
+        };
+        unsafe {
+            cvt(
+                "xrLocateBodyJointsFB",
+                (self.fp().locate_body_joints)(self.as_raw(), &info, &mut raw),
+            )?;
+        }
+        locations.is_active = raw.is_active.into();
+        locations.confidence = raw.confidence;
+        locations.skeleton_changed_count = raw.skeleton_changed_count;
+        locations.time = raw.time;
+        Ok(locations)
+    }
+
+    /// Get the user's skeleton in its T-pose, one entry per joint
+    ///
+    /// Should be fetched again whenever `BodyJointLocations::skeleton_changed_count` changes.
+    #[inline]
+    pub fn skeleton(&self) -> Result<Vec<BodySkeletonJointFB>> {
+        let mut joints = Vec::with_capacity(BODY_JOINT_COUNT);
+        let mut raw = sys::BodySkeletonFB {
+            ty: sys::BodySkeletonFB::TYPE,
+            next: ptr::null_mut(),
+            joint_count: BODY_JOINT_COUNT as u32,
+            joints: joints.as_mut_ptr(),
+        };
+        unsafe {
+            cvt(
+                "xrGetBodySkeletonFB",
+                (self.fp().get_body_skeleton)(self.as_raw(), &mut raw),
+            )?;
+            joints.set_len(BODY_JOINT_COUNT);
+        }
+        Ok(joints)
+    }
+
+    // Private helper
+    #[inline]
+    fn fp(&self) -> &raw::BodyTrackingFB {
+        self.instance()
+            .exts()
+            .fb_body_tracking
+            .as_ref()
+            .expect("FB_body_tracking not loaded")
+    }
+}
+
+impl Drop for BodyTracker {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp().destroy_body_tracker)(self.handle);
+        }
+    }
+}
+
+/// Locations of every joint of a body, obtained from `BodyTracker::locate`
+#[derive(Copy, Clone)]
+pub struct BodyJointLocations {
+    /// Whether the body is currently being tracked
+    pub is_active: bool,
+    /// Confidence of the estimate, from 0 to 1
+    pub confidence: f32,
+    /// Incremented whenever the skeleton reported by `BodyTracker::skeleton` changes
+    pub skeleton_changed_count: u32,
+    /// Time at which the joints were located
+    pub time: Time,
+    /// Pose and location flags of each joint, indexed by `BodyJointFB`
+    pub joints: [BodyJointLocationFB; BODY_JOINT_COUNT],
+}
@@ -0,0 +1,126 @@
+use std::{
+    borrow::Cow,
+    ffi::CStr,
+    os::raw::{c_char, c_void},
+    panic,
+    sync::Mutex,
+};
+
+use crate::*;
+
+/// Closure invoked by a `DebugUtilsMessenger`
+pub type DebugUtilsCallback = Box<dyn FnMut(&DebugUtilsMessage<'_>) + Send>;
+
+/// Delivers messages from the runtime and API layers to a closure until dropped
+///
+/// Created by `Instance::create_debug_utils_messenger`. Requires `XR_EXT_debug_utils`.
+pub struct DebugUtilsMessenger {
+    instance: Instance,
+    handle: sys::DebugUtilsMessengerEXT,
+    // Referenced by the runtime as the messenger's user data
+    _callback: Box<Mutex<DebugUtilsCallback>>,
+}
+
+impl DebugUtilsMessenger {
+    pub(crate) unsafe fn new(
+        instance: &Instance,
+        severities: DebugUtilsMessageSeverityFlagsEXT,
+        types: DebugUtilsMessageTypeFlagsEXT,
+        callback: DebugUtilsCallback,
+    ) -> Result<Self> {
+        let fp = instance
+            .exts()
+            .ext_debug_utils
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let callback = Box::new(Mutex::new(callback));
+        let info = sys::DebugUtilsMessengerCreateInfoEXT {
+            ty: sys::DebugUtilsMessengerCreateInfoEXT::TYPE,
+            next: std::ptr::null(),
+            message_severities: severities,
+            message_types: types,
+            user_callback: Some(debug_utils_callback),
+            user_data: &*callback as *const Mutex<DebugUtilsCallback> as *mut c_void,
+        };
+        let mut out = sys::DebugUtilsMessengerEXT::NULL;
+        cvt(
+            "xrCreateDebugUtilsMessengerEXT",
+            (fp.create_debug_utils_messenger)(instance.as_raw(), &info, &mut out),
+        )?;
+        Ok(Self {
+            instance: instance.clone(),
+            handle: out,
+            _callback: callback,
+        })
+    }
+
+    /// Access the raw messenger handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::DebugUtilsMessengerEXT {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+}
+
+impl Drop for DebugUtilsMessenger {
+    fn drop(&mut self) {
+        unsafe {
+            (self
+                .instance
+                .exts()
+                .ext_debug_utils
+                .as_ref()
+                .expect("EXT_debug_utils not loaded")
+                .destroy_debug_utils_messenger)(self.handle);
+        }
+    }
+}
+
+/// A message delivered to a `DebugUtilsMessenger`
+#[derive(Debug, Clone)]
+pub struct DebugUtilsMessage<'a> {
+    pub severity: DebugUtilsMessageSeverityFlagsEXT,
+    pub ty: DebugUtilsMessageTypeFlagsEXT,
+    /// Identifies the kind of message, e.g. the validation rule violated, if any
+    pub message_id: Option<Cow<'a, str>>,
+    /// The OpenXR function that triggered the message, if any
+    pub function_name: Option<Cow<'a, str>>,
+    pub message: Cow<'a, str>,
+}
+
+unsafe extern "system" fn debug_utils_callback(
+    severity: DebugUtilsMessageSeverityFlagsEXT,
+    ty: DebugUtilsMessageTypeFlagsEXT,
+    data: *const sys::DebugUtilsMessengerCallbackDataEXT,
+    user_data: *mut c_void,
+) -> sys::Bool32 {
+    let str_opt = |x: *const c_char| {
+        if x.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(x).to_string_lossy())
+        }
+    };
+    let data = &*data;
+    let message = DebugUtilsMessage {
+        severity,
+        ty,
+        message_id: str_opt(data.message_id),
+        function_name: str_opt(data.function_name),
+        message: str_opt(data.message).unwrap_or_default(),
+    };
+    let callback = &*(user_data as *const Mutex<DebugUtilsCallback>);
+    // Unwinding into the runtime is undefined behavior
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        if let Ok(mut callback) = callback.lock() {
+            callback(&message);
+        }
+    }));
+    // Applications must not request that the triggering call be aborted
+    false.into()
+}
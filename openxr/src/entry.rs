@@ -1,8 +1,13 @@
 #[cfg(feature = "loaded")]
 use shared_library::dynamic_library::DynamicLibrary;
-use std::{ffi::CStr, mem, ptr, sync::Arc};
+use std::os::raw::c_void;
+use std::{
+    ffi::{CStr, CString},
+    mem, ptr,
+    sync::Arc,
+};
 #[cfg(feature = "loaded")]
-use std::{fmt, os::raw::c_void, path::Path};
+use std::{fmt, path::Path};
 
 use crate::*;
 
@@ -102,11 +107,10 @@ impl Entry {
         name: &CStr,
     ) -> Result<unsafe extern "system" fn()> {
         let mut f = None;
-        cvt((self.fp().get_instance_proc_addr)(
-            instance,
-            name.as_ptr(),
-            &mut f,
-        ))?;
+        cvt(
+            "xrGetInstanceProcAddr",
+            (self.fp().get_instance_proc_addr)(instance, name.as_ptr(), &mut f),
+        )?;
         Ok(f.unwrap())
     }
 
@@ -118,6 +122,65 @@ impl Entry {
         &self,
         app_info: &ApplicationInfo,
         required_extensions: &ExtensionSet,
+    ) -> Result<Instance> {
+        self.create_instance_with_layers(app_info, required_extensions, &[])
+    }
+
+    /// Create an OpenXR instance with certain extensions and API layers enabled
+    ///
+    /// `layers` are names such as `XR_APILAYER_LUNARG_core_validation`, as reported by
+    /// `enumerate_api_layers`. Layers are inserted between the application and the runtime in the
+    /// order given.
+    pub fn create_instance_with_layers(
+        &self,
+        app_info: &ApplicationInfo,
+        required_extensions: &ExtensionSet,
+        layers: &[&str],
+    ) -> Result<Instance> {
+        unsafe { self.create_instance_inner(app_info, required_extensions, layers, ptr::null()) }
+    }
+
+    /// Create an OpenXR instance on Android, where the runtime must be told the application's Java
+    /// VM and activity
+    ///
+    /// `XR_KHR_android_create_instance` is enabled in addition to `required_extensions`. On
+    /// standalone devices `initialize_android_loader` must have been called first.
+    ///
+    /// # Safety
+    ///
+    /// `vm` must be a valid `JavaVM*`, and `activity` a valid global reference to the application's
+    /// `android.app.Activity` jobject, both remaining valid for the lifetime of the instance.
+    #[cfg(target_os = "android")]
+    pub unsafe fn create_instance_android(
+        &self,
+        app_info: &ApplicationInfo,
+        required_extensions: &ExtensionSet,
+        layers: &[&str],
+        vm: *mut c_void,
+        activity: *mut c_void,
+    ) -> Result<Instance> {
+        let mut required_extensions = required_extensions.clone();
+        required_extensions.khr_android_create_instance = true;
+        let android_info = sys::InstanceCreateInfoAndroidKHR {
+            ty: sys::InstanceCreateInfoAndroidKHR::TYPE,
+            next: ptr::null(),
+            application_vm: vm,
+            application_activity: activity,
+        };
+        self.create_instance_inner(
+            app_info,
+            &required_extensions,
+            layers,
+            &android_info as *const _ as _,
+        )
+    }
+
+    unsafe fn create_instance_inner(
+        &self,
+        app_info: &ApplicationInfo,
+        required_extensions: &ExtensionSet,
+        layers: &[&str],
+        next: *const c_void,
     ) -> Result<Instance> {
         assert!(
             app_info.application_name.len() < sys::MAX_APPLICATION_NAME_SIZE,
@@ -129,20 +192,29 @@ impl Entry {
             "engine names are limited to {} bytes",
             sys::MAX_ENGINE_NAME_SIZE
         );
+        if app_info.api_version.major() != CURRENT_API_VERSION.major() {
+            // These bindings can't drive another major version, whatever the runtime supports
+            return Err(error(None, sys::Result::ERROR_API_VERSION_UNSUPPORTED));
+        }
         let ext_names = required_extensions.names();
+        let layer_names = layers
+            .iter()
+            .map(|&x| CString::new(x).unwrap())
+            .collect::<Vec<_>>();
+        let layer_ptrs = layer_names.iter().map(|x| x.as_ptr()).collect::<Vec<_>>();
         let mut info = sys::InstanceCreateInfo {
             ty: sys::InstanceCreateInfo::TYPE,
-            next: ptr::null(),
+            next,
             create_flags: Default::default(),
             application_info: sys::ApplicationInfo {
                 application_name: [0; sys::MAX_APPLICATION_NAME_SIZE],
                 application_version: app_info.application_version,
                 engine_name: [0; sys::MAX_ENGINE_NAME_SIZE],
                 engine_version: app_info.engine_version,
-                api_version: CURRENT_API_VERSION,
+                api_version: app_info.api_version,
             },
-            enabled_api_layer_count: 0,
-            enabled_api_layer_names: ptr::null(),
+            enabled_api_layer_count: layer_ptrs.len() as _,
+            enabled_api_layer_names: layer_ptrs.as_ptr(),
             enabled_extension_count: ext_names.len() as _,
             enabled_extension_names: ext_names.as_ptr(),
         };
@@ -154,19 +226,21 @@ impl Entry {
             &mut info.application_info.engine_name,
             &app_info.engine_name,
         );
-        unsafe {
-            let mut handle = sys::Instance::NULL;
-            cvt((self.fp().create_instance)(&info, &mut handle))?;
+        let mut handle = sys::Instance::NULL;
+        cvt(
+            "xrCreateInstance",
+            (self.fp().create_instance)(&info, &mut handle),
+        )?;
 
-            let exts = InstanceExtensions::load(self, handle, required_extensions)?;
-            Instance::from_raw(self.clone(), handle, exts)
-        }
+        let exts = InstanceExtensions::load(self, handle, required_extensions)?;
+        Instance::from_raw_with_extensions(self.clone(), handle, exts, required_extensions.clone())
     }
 
     /// Determine the set of extensions supported by this OpenXR implementation
     pub fn enumerate_extensions(&self) -> Result<ExtensionSet> {
         unsafe {
             let exts = get_arr_init(
+                "xrEnumerateInstanceExtensionProperties",
                 sys::ExtensionProperties::out(ptr::null_mut()),
                 |cap, count, buf| {
                     (self.fp().enumerate_instance_extension_properties)(
@@ -180,6 +254,63 @@ impl Entry {
             Ok(ExtensionSet::from_properties(mem::transmute(&exts[..])))
         }
     }
+
+    /// Initialize the loader with the application's Java VM and Android `Context`
+    ///
+    /// Android loaders require this, via `XR_KHR_loader_init_android`, before any other call
+    /// including `enumerate_extensions` and `create_instance`. The extension need not be enabled
+    /// on the resulting instance.
+    ///
+    /// # Safety
+    ///
+    /// `vm` must be a valid `JavaVM*`, and `context` a valid global reference to the application's
+    /// `android.content.Context` jobject, both remaining valid for the lifetime of the loader.
+    #[cfg(target_os = "android")]
+    pub unsafe fn initialize_android_loader(
+        &self,
+        vm: *mut c_void,
+        context: *mut c_void,
+    ) -> Result<()> {
+        let initialize_loader: sys::pfn::InitializeLoaderKHR =
+            mem::transmute(self.get_instance_proc_addr(
+                sys::Instance::NULL,
+                CStr::from_bytes_with_nul_unchecked(b"xrInitializeLoaderKHR\0"),
+            )?);
+        let info = sys::LoaderInitInfoAndroidKHR {
+            ty: sys::LoaderInitInfoAndroidKHR::TYPE,
+            next: ptr::null(),
+            application_vm: vm,
+            application_context: context,
+        };
+        cvt(
+            "xrInitializeLoaderKHR",
+            initialize_loader(&info as *const _ as _),
+        )?;
+        Ok(())
+    }
+
+    /// Determine the set of API layers available to this OpenXR implementation
+    pub fn enumerate_api_layers(&self) -> Result<Vec<ApiLayerProperties>> {
+        let layers = get_arr_init(
+            "xrEnumerateApiLayerProperties",
+            sys::ApiLayerProperties::out(ptr::null_mut()),
+            |cap, count, buf| unsafe {
+                (self.fp().enumerate_api_layer_properties)(cap, count, buf as _)
+            },
+        )?;
+        Ok(layers
+            .iter()
+            .map(|x| unsafe {
+                let x = &*x.as_ptr();
+                ApiLayerProperties {
+                    layer_name: fixed_str(&x.layer_name).into(),
+                    spec_version: x.spec_version,
+                    layer_version: x.layer_version,
+                    description: fixed_str(&x.description).into(),
+                }
+            })
+            .collect())
+    }
 }
 
 struct Inner {
@@ -217,10 +348,42 @@ impl fmt::Display for LoadError {
 #[cfg(feature = "loaded")]
 impl std::error::Error for LoadError {}
 
-#[derive(Debug, Copy, Clone, Default)]
+/// Describes an API layer which may be enabled with `Entry::create_instance_with_layers`
+#[derive(Debug, Clone)]
+pub struct ApiLayerProperties {
+    pub layer_name: String,
+    /// Version of OpenXR the layer was written against
+    pub spec_version: Version,
+    /// Layer-defined version, not necessarily related to the OpenXR version
+    pub layer_version: u32,
+    pub description: String,
+}
+
+#[derive(Debug, Copy, Clone)]
 pub struct ApplicationInfo<'a> {
     pub application_name: &'a str,
     pub application_version: u32,
     pub engine_name: &'a str,
     pub engine_version: u32,
+    /// Version of OpenXR the application targets, defaulting to `CURRENT_API_VERSION`
+    ///
+    /// Only the major and minor versions are significant. A major version other than that of
+    /// `CURRENT_API_VERSION` is rejected by these bindings with `ERROR_API_VERSION_UNSUPPORTED`,
+    /// recording no function name. Any minor version is passed to the runtime as is: runtimes
+    /// which do not support it fail `xrCreateInstance` with `ERROR_API_VERSION_UNSUPPORTED`, after
+    /// which an older minor version may be tried. Core functions added after the requested minor
+    /// version must not be used.
+    pub api_version: Version,
+}
+
+impl Default for ApplicationInfo<'_> {
+    fn default() -> Self {
+        Self {
+            application_name: "",
+            application_version: 0,
+            engine_name: "",
+            engine_version: 0,
+            api_version: CURRENT_API_VERSION,
+        }
+    }
 }
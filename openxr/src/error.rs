@@ -0,0 +1,104 @@
+use std::{cell::Cell, fmt};
+
+use crate::*;
+
+/// An error returned by an OpenXR function, along with the name of that function
+///
+/// Methods still return plain `sys::Result` errors. Convert one with `Error::from`, e.g.
+/// `session.end().map_err(xr::Error::from)?`, on the thread that received it to recover the
+/// function which produced it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Error {
+    /// The code returned by the runtime, or synthesized by these bindings
+    pub result: sys::Result,
+    /// The OpenXR function which produced `result`, e.g. `"xrEndFrame"`
+    ///
+    /// `None` if the error was synthesized by these bindings, e.g. `ERROR_EXTENSION_NOT_PRESENT`
+    /// for a method whose extension was not enabled, or if another call has failed on this thread
+    /// since.
+    pub function: Option<&'static str>,
+}
+
+impl Error {
+    #[inline]
+    pub fn new(function: Option<&'static str>, result: sys::Result) -> Self {
+        Self { result, function }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(function) = self.function {
+            write!(fmt, "{}: ", function)?;
+        }
+        write!(fmt, "{:?}: {}", self.result, self.result)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.result)
+    }
+}
+
+thread_local! {
+    /// The most recent error returned by these bindings on this thread, and its source
+    // `const` thread-local initializers require Rust 1.59
+    #[allow(clippy::missing_const_for_thread_local)]
+    static LAST_ERROR: Cell<(sys::Result, Option<&'static str>)> =
+        Cell::new((sys::Result::SUCCESS, None));
+}
+
+/// Record that `function` produced `result` on this thread, for `Error::from`
+pub(crate) fn record_error(function: Option<&'static str>, result: sys::Result) {
+    let _ = LAST_ERROR.try_with(|x| x.set((result, function)));
+}
+
+/// The function which produced `result`, if it is the most recent error on this thread
+fn last_function(result: sys::Result) -> Option<&'static str> {
+    let (last, function) = LAST_ERROR.try_with(Cell::get).ok()?;
+    if last == result {
+        function
+    } else {
+        None
+    }
+}
+
+impl From<sys::Result> for Error {
+    /// Attributes `result` to the function which most recently produced it on this thread
+    #[inline]
+    fn from(result: sys::Result) -> Self {
+        Self::new(last_function(result), result)
+    }
+}
+
+impl From<Error> for sys::Result {
+    #[inline]
+    fn from(error: Error) -> Self {
+        error.result
+    }
+}
+
+impl PartialEq<sys::Result> for Error {
+    #[inline]
+    fn eq(&self, other: &sys::Result) -> bool {
+        self.result == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attributes_most_recent_error() {
+        let result = error(Some("xrEndFrame"), sys::Result::ERROR_CALL_ORDER_INVALID);
+        let err = Error::from(result);
+        assert_eq!(err.function, Some("xrEndFrame"));
+        assert_eq!(err.to_string().split(':').next(), Some("xrEndFrame"));
+        // A different code isn't attributed to an unrelated call
+        assert_eq!(Error::from(sys::Result::ERROR_SESSION_LOST).function, None);
+        error(None, sys::Result::ERROR_CALL_ORDER_INVALID);
+        assert_eq!(Error::from(result).function, None);
+    }
+}
@@ -0,0 +1,120 @@
+use std::{ptr, sync::Arc};
+
+use crate::*;
+
+/// Tracks the gaze of each of the user's eyes individually, e.g. to animate an avatar
+///
+/// Created by `Session::create_eye_tracker`. Requires `XR_FB_eye_tracking_social`. Unlike
+/// `XR_EXT_eye_gaze_interaction`, which reports a single combined gaze for interaction, this
+/// reports raw per-eye data.
+pub struct EyeTracker {
+    session: Arc<session::SessionInner>,
+    handle: sys::EyeTrackerFB,
+}
+
+impl EyeTracker {
+    /// Take ownership of an existing eye tracker handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid eye tracker handle associated with `session`, which must have
+    /// been created with `XR_FB_eye_tracking_social` enabled.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(session: Session<G>, handle: sys::EyeTrackerFB) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+        }
+    }
+
+    /// Access the raw eye tracker handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::EyeTrackerFB {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// Determine the gaze of each eye relative to `base` at `time`
+    ///
+    /// The gaze of an eye is only meaningful when its `EyeGaze::is_valid` is set.
+    #[inline]
+    pub fn get_eye_gazes(&self, base: &Space, time: Time) -> Result<EyeGazes> {
+        // This assert allows this function to be safe.
+        assert_eq!(&*self.session as *const session::SessionInner, &*base.session as *const session::SessionInner,
+                   "`self` and `base` must have been created, allocated, or retrieved from the same `Session`");
+        let info = sys::EyeGazesInfoFB {
+            ty: sys::EyeGazesInfoFB::TYPE,
+            next: ptr::null(),
+            base_space: base.as_raw(),
+            time,
+        };
+        let out = unsafe {
+            let mut x = sys::EyeGazesFB::out(ptr::null_mut());
+            cvt(
+                "xrGetEyeGazesFB",
+                (self.fp().get_eye_gazes)(self.as_raw(), &info, x.as_mut_ptr()),
+            )?;
+            x.assume_init()
+        };
+        let gaze = |x: &sys::EyeGazeFB| EyeGaze {
+            is_valid: x.is_valid.into(),
+            pose: x.gaze_pose,
+            confidence: x.gaze_confidence,
+        };
+        Ok(EyeGazes {
+            gazes: [gaze(&out.gaze[0]), gaze(&out.gaze[1])],
+            time: out.time,
+        })
+    }
+
+    // Private helper
+    #[inline]
+    fn fp(&self) -> &raw::EyeTrackingSocialFB {
+        self.instance()
+            .exts()
+            .fb_eye_tracking_social
+            .as_ref()
+            .expect("FB_eye_tracking_social not loaded")
+    }
+}
+
+impl Drop for EyeTracker {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp().destroy_eye_tracker)(self.handle);
+        }
+    }
+}
+
+/// Gaze of both eyes, obtained from `EyeTracker::get_eye_gazes`
+#[derive(Copy, Clone)]
+pub struct EyeGazes {
+    /// Gaze of each eye, indexed by `EyePositionFB`
+    pub gazes: [EyeGaze; 2],
+    /// Time at which the gazes were sampled
+    pub time: Time,
+}
+
+impl EyeGazes {
+    /// Gaze of a single eye
+    #[inline]
+    pub fn gaze(&self, eye: EyePositionFB) -> &EyeGaze {
+        &self.gazes[eye.into_raw() as usize]
+    }
+}
+
+/// Gaze of a single eye
+#[derive(Copy, Clone)]
+pub struct EyeGaze {
+    /// Whether `pose` and `confidence` hold valid data, e.g. false while the eye is closed
+    pub is_valid: bool,
+    /// Origin at the eye's center, looking down its -Z axis
+    pub pose: Posef,
+    /// Confidence of the estimate, from 0 to 1
+    pub confidence: f32,
+}
@@ -0,0 +1,140 @@
+use std::{ptr, sync::Arc};
+
+use crate::*;
+
+/// Number of weights reported by `FaceTracker2::get_expression_weights`, i.e.
+/// `FaceExpression2FB::COUNT`
+pub const FACE_EXPRESSION2_COUNT: usize = 70;
+
+/// Number of confidences reported by `FaceTracker2::get_expression_weights`, i.e.
+/// `FaceConfidence2FB::COUNT`
+pub const FACE_CONFIDENCE2_COUNT: usize = 2;
+
+/// Tracks the user's facial expression as a set of blendshape weights
+///
+/// Created by `Session::create_face_tracker2`. Requires `XR_FB_face_tracking2`.
+pub struct FaceTracker2 {
+    session: Arc<session::SessionInner>,
+    handle: sys::FaceTracker2FB,
+}
+
+impl FaceTracker2 {
+    /// Take ownership of an existing face tracker handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid face tracker handle associated with `session`, which must have
+    /// been created with `XR_FB_face_tracking2` enabled and `FaceExpressionSet2FB::DEFAULT`.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(session: Session<G>, handle: sys::FaceTracker2FB) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+        }
+    }
+
+    /// Access the raw face tracker handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::FaceTracker2FB {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// Get the weight of each blendshape of the user's face at `time`
+    ///
+    /// Weights are only meaningful when `FaceExpressionWeights2::is_valid` is set.
+    #[inline]
+    pub fn get_expression_weights(&self, time: Time) -> Result<FaceExpressionWeights2> {
+        let info = sys::FaceExpressionInfo2FB {
+            ty: sys::FaceExpressionInfo2FB::TYPE,
+            next: ptr::null(),
+            time,
+        };
+        // Arrays are written in full by the runtime on success
+        let mut weights = FaceExpressionWeights2 {
+            weights: [0.0; FACE_EXPRESSION2_COUNT],
+            confidences: [0.0; FACE_CONFIDENCE2_COUNT],
+            is_valid: false,
+            is_eye_following_blendshapes_valid: false,
+            data_source: FaceTrackingDataSource2FB::VISUAL,
+            time,
+        };
+        let mut raw = sys::FaceExpressionWeights2FB {
+            ty: sys::FaceExpressionWeights2FB::TYPE,
+            next: ptr::null_mut(),
+            weight_count: FACE_EXPRESSION2_COUNT as u32,
+            weights: weights.weights.as_mut_ptr(),
+            confidence_count: FACE_CONFIDENCE2_COUNT as u32,
+            confidences: weights.confidences.as_mut_ptr(),
+            is_valid: false.into(),
+            is_eye_following_blendshapes_valid: false.into(),
+            data_source: FaceTrackingDataSource2FB::VISUAL,
+            time,
+        };
+        unsafe {
+            cvt(
+                "xrGetFaceExpressionWeights2FB",
+                (self.fp().get_face_expression_weights2)(self.as_raw(), &info, &mut raw),
+            )?;
+        }
+        weights.is_valid = raw.is_valid.into();
+        weights.is_eye_following_blendshapes_valid = raw.is_eye_following_blendshapes_valid.into();
+        weights.data_source = raw.data_source;
+        weights.time = raw.time;
+        Ok(weights)
+    }
+
+    // Private helper
+    #[inline]
+    fn fp(&self) -> &raw::FaceTracking2FB {
+        self.instance()
+            .exts()
+            .fb_face_tracking2
+            .as_ref()
+            .expect("FB_face_tracking2 not loaded")
+    }
+}
+
+impl Drop for FaceTracker2 {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp().destroy_face_tracker2)(self.handle);
+        }
+    }
+}
+
+/// Blendshape weights of a face, obtained from `FaceTracker2::get_expression_weights`
+#[derive(Copy, Clone)]
+pub struct FaceExpressionWeights2 {
+    /// Weight of each blendshape, from 0 to 1, indexed by `FaceExpression2FB`
+    pub weights: [f32; FACE_EXPRESSION2_COUNT],
+    /// Confidence of the upper and lower face estimates, indexed by `FaceConfidence2FB`
+    pub confidences: [f32; FACE_CONFIDENCE2_COUNT],
+    /// Whether `weights` and `confidences` hold valid data
+    pub is_valid: bool,
+    /// Whether the eye-looking blendshapes follow the user's eye gaze
+    pub is_eye_following_blendshapes_valid: bool,
+    /// Which of the requested data sources produced the weights
+    pub data_source: FaceTrackingDataSource2FB,
+    /// Time at which the weights were sampled
+    pub time: Time,
+}
+
+impl FaceExpressionWeights2 {
+    /// Weight of a single blendshape
+    #[inline]
+    pub fn weight(&self, expression: FaceExpression2FB) -> f32 {
+        self.weights[expression.into_raw() as usize]
+    }
+
+    /// Confidence of a single region of the face
+    #[inline]
+    pub fn confidence(&self, region: FaceConfidence2FB) -> f32 {
+        self.confidences[region.into_raw() as usize]
+    }
+}
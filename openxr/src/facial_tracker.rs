@@ -0,0 +1,147 @@
+use std::{ptr, sync::Arc};
+
+use crate::*;
+
+/// Tracks the user's eye or lip expressions as a set of blendshape weights
+///
+/// Created by `Session::create_facial_tracker`. Requires `XR_HTC_facial_tracking`.
+pub struct FacialTracker {
+    session: Arc<session::SessionInner>,
+    handle: sys::FacialTrackerHTC,
+    ty: FacialTrackingTypeHTC,
+}
+
+impl FacialTracker {
+    /// Take ownership of an existing facial tracker handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid facial tracker handle of type `ty` associated with `session`,
+    /// which must have been created with `XR_HTC_facial_tracking` enabled.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(
+        session: Session<G>,
+        handle: sys::FacialTrackerHTC,
+        ty: FacialTrackingTypeHTC,
+    ) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+            ty,
+        }
+    }
+
+    /// Access the raw facial tracker handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::FacialTrackerHTC {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// The kind of expressions tracked
+    #[inline]
+    pub fn tracking_type(&self) -> FacialTrackingTypeHTC {
+        self.ty
+    }
+
+    /// Number of weights reported by `get_expressions`, i.e. `FACIAL_EXPRESSION_EYE_COUNT_HTC` for
+    /// eye trackers and `FACIAL_EXPRESSION_LIP_COUNT_HTC` for lip trackers
+    #[inline]
+    pub fn expression_count(&self) -> usize {
+        match self.ty {
+            FacialTrackingTypeHTC::EYE_DEFAULT => sys::FACIAL_EXPRESSION_EYE_COUNT_HTC,
+            FacialTrackingTypeHTC::LIP_DEFAULT => sys::FACIAL_EXPRESSION_LIP_COUNT_HTC,
+            _ => 0,
+        }
+    }
+
+    /// Get the weight of each blendshape at `time`
+    ///
+    /// Weights are only meaningful when `FacialExpressions::is_active` is set.
+    #[inline]
+    pub fn get_expressions(&self, time: Time) -> Result<FacialExpressions> {
+        let mut weights = vec![0.0; self.expression_count()];
+        let mut raw = sys::FacialExpressionsHTC {
+            ty: sys::FacialExpressionsHTC::TYPE,
+            next: ptr::null(),
+            is_active: false.into(),
+            sample_time: time,
+            expression_count: weights.len() as u32,
+            expression_weightings: weights.as_mut_ptr(),
+        };
+        unsafe {
+            cvt(
+                "xrGetFacialExpressionsHTC",
+                (self.fp().get_facial_expressions)(self.as_raw(), &mut raw),
+            )?;
+        }
+        Ok(FacialExpressions {
+            is_active: raw.is_active.into(),
+            sample_time: raw.sample_time,
+            weights,
+        })
+    }
+
+    // Private helper
+    #[inline]
+    fn fp(&self) -> &raw::FacialTrackingHTC {
+        self.instance()
+            .exts()
+            .htc_facial_tracking
+            .as_ref()
+            .expect("HTC_facial_tracking not loaded")
+    }
+}
+
+impl Drop for FacialTracker {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp().destroy_facial_tracker)(self.handle);
+        }
+    }
+}
+
+/// Blendshape weights obtained from `FacialTracker::get_expressions`
+#[derive(Clone)]
+pub struct FacialExpressions {
+    /// Whether the expressions are currently being tracked
+    pub is_active: bool,
+    /// Time at which the weights were sampled
+    pub sample_time: Time,
+    /// Weight of each blendshape, from 0 to 1, with length `FacialTracker::expression_count`
+    pub weights: Vec<f32>,
+}
+
+impl FacialExpressions {
+    /// Weight of an eye blendshape, if obtained from an `EYE_DEFAULT` tracker
+    #[inline]
+    pub fn eye(&self, expression: EyeExpressionHTC) -> Option<f32> {
+        match self.weights.len() {
+            sys::FACIAL_EXPRESSION_EYE_COUNT_HTC => self.weight(expression.into_raw()),
+            _ => None,
+        }
+    }
+
+    /// Weight of a lip blendshape, if obtained from a `LIP_DEFAULT` tracker
+    #[inline]
+    pub fn lip(&self, expression: LipExpressionHTC) -> Option<f32> {
+        match self.weights.len() {
+            sys::FACIAL_EXPRESSION_LIP_COUNT_HTC => self.weight(expression.into_raw()),
+            _ => None,
+        }
+    }
+
+    // Private helper
+    #[inline]
+    fn weight(&self, index: i32) -> Option<f32> {
+        if index < 0 {
+            return None;
+        }
+        self.weights.get(index as usize).copied()
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use crate::*;
+
+/// A foveation configuration that can be applied to swapchains
+///
+/// Created by `Session::create_foveation_profile` and applied with
+/// `Swapchain::update_foveation`. Requires `XR_FB_foveation`.
+pub struct FoveationProfile {
+    session: Arc<session::SessionInner>,
+    handle: sys::FoveationProfileFB,
+}
+
+impl FoveationProfile {
+    /// Take ownership of an existing foveation profile handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid foveation profile handle associated with `session`, which must
+    /// have been created with `XR_FB_foveation` enabled.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(
+        session: Session<G>,
+        handle: sys::FoveationProfileFB,
+    ) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+        }
+    }
+
+    /// Access the raw foveation profile handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::FoveationProfileFB {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+}
+
+impl Drop for FoveationProfile {
+    fn drop(&mut self) {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_foveation
+            .as_ref()
+            .expect("FB_foveation not loaded");
+        unsafe {
+            (fp.destroy_foveation_profile)(self.handle);
+        }
+    }
+}
+
+/// Settings for a fixed foveation profile, as defined by `XR_FB_foveation_configuration`
+#[derive(Debug, Copy, Clone)]
+pub struct FoveationLevelProfile {
+    /// Maximum amount of foveation to apply
+    pub level: FoveationLevelFB,
+    /// Vertical offset of the foveation center, in degrees, with positive values moving it up
+    pub vertical_offset: f32,
+    /// Whether the runtime may lower the foveation level when there is performance headroom
+    pub dynamic: FoveationDynamicFB,
+    /// Whether the foveation center follows the user's gaze
+    ///
+    /// Requires `XR_META_foveation_eye_tracked`, and a system reporting
+    /// `SystemProperties::supports_foveation_eye_tracked`.
+    pub eye_tracked: bool,
+}
+
+/// Where eye-tracked foveation is centered, obtained from
+/// `Session::get_foveation_eye_tracked_state`
+#[derive(Copy, Clone)]
+pub struct FoveationEyeTrackedState {
+    /// Center of foveation for each eye, in normalized device coordinates
+    pub foveation_center: [Vector2f; sys::FOVEATION_CENTER_SIZE_META],
+    pub flags: FoveationEyeTrackedStateFlagsMETA,
+}
+
+impl FoveationEyeTrackedState {
+    /// Whether `foveation_center` is meaningful
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.flags
+            .contains(FoveationEyeTrackedStateFlagsMETA::VALID)
+    }
+}
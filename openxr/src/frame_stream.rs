@@ -1,4 +1,4 @@
-use std::ptr;
+use std::{mem, ptr};
 
 use crate::*;
 
@@ -80,18 +80,41 @@ use crate::*;
 /// ```
 pub struct FrameStream<G: Graphics> {
     session: Session<G>,
+    /// Whether the next frame ended should request local dimming
+    local_dimming: bool,
 }
 
 impl<G: Graphics> FrameStream<G> {
     pub(crate) fn new(session: Session<G>) -> Self {
-        Self { session }
+        Self {
+            session,
+            local_dimming: false,
+        }
+    }
+
+    /// Request local dimming for the next frame ended by `end` or `end_secondary`
+    ///
+    /// Local dimming improves contrast of dark scenes on displays which support it. It only
+    /// affects the frame it is submitted with, so should be requested anew for each frame, e.g.
+    /// `frame_stream.local_dimming(true)?.end(...)`. Requesting local dimming returns
+    /// `ERROR_EXTENSION_NOT_PRESENT` if `XR_META_local_dimming` was not enabled on the instance.
+    #[inline]
+    pub fn local_dimming(&mut self, enabled: bool) -> Result<&mut Self> {
+        if enabled && self.session.instance().exts().meta_local_dimming.is_none() {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        self.local_dimming = enabled;
+        Ok(self)
     }
 
     /// Indicate that graphics device work is beginning
     #[inline]
     pub fn begin(&mut self) -> Result<()> {
         unsafe {
-            cvt((self.fp().begin_frame)(self.session.as_raw(), ptr::null()))?;
+            cvt(
+                "xrBeginFrame",
+                (self.fp().begin_frame)(self.session.as_raw(), ptr::null()),
+            )?;
         }
         Ok(())
     }
@@ -99,7 +122,50 @@ impl<G: Graphics> FrameStream<G> {
     /// Indicate that all graphics work for the frame has been submitted
     ///
     /// `layers` is an array of references to any type of composition layer,
-    /// e.g. `CompositionLayerProjection`.
+    /// e.g. `CompositionLayerProjection`, in back-to-front order. It may be empty, e.g. if
+    /// `FrameState::should_render` was false, in which case nothing is displayed for this frame.
+    /// Layers of different types may be mixed freely, e.g. `&[&projection, &hud_quad]`.
+    ///
+    /// With `XR_KHR_composition_layer_depth`, depth for each projection view may be supplied with
+    /// `CompositionLayerProjectionView::depth_info_khr`, typically from a swapchain created by
+    /// `Session::create_depth_swapchain`. `near_z` may be greater than `far_z` for reversed-Z
+    /// depth buffers.
+    ///
+    /// With `XR_FB_space_warp`, motion vectors and depth for each projection view may instead be
+    /// supplied with `CompositionLayerProjectionView::space_warp_info_fb`; motion vector images
+    /// should be sized according to `SystemProperties::space_warp`. A view carries at most one of
+    /// these chained structures.
+    ///
+    /// With `XR_FB_composition_layer_settings`, any layer may opt into the compositor's
+    /// super-sampling or sharpening, e.g. for clearer text, by chaining a
+    /// `CompositionLayerSettingsFB` with flags such as
+    /// `CompositionLayerSettingsFlagsFB::NORMAL_SHARPENING` using the layer's `settings_fb` setter.
+    /// There is no shorthand taking the flags directly, because layers only borrow the structures
+    /// chained onto them and so cannot hold a `CompositionLayerSettingsFB` of their own.
+    ///
+    /// With `XR_FB_composition_layer_alpha_blend`, a layer's blending with the layers behind it may
+    /// be customized by chaining a `CompositionLayerAlphaBlendFB` using the layer's
+    /// `alpha_blend_fb` setter. The factors apply to the layer's color as submitted, which is
+    /// premultiplied by alpha unless the layer has `CompositionLayerFlags::UNPREMULTIPLIED_ALPHA`;
+    /// conventional premultiplied blending uses `BlendFactorFB::ONE` and `ONE_MINUS_SRC_ALPHA`.
+    /// All four factors should be set, as each defaults to `ZERO`.
+    ///
+    /// Each of these setters replaces whatever was chained onto the layer before. To chain several
+    /// structures onto one layer, chain them onto each other and pass the first to the layer:
+    ///
+    /// ```no_run
+    /// # fn dummy<G: openxr::Graphics>(quad: openxr::CompositionLayerQuad<'_, G>) {
+    /// let blend = openxr::CompositionLayerAlphaBlendFB::new()
+    ///     .src_factor_color(openxr::BlendFactorFB::ONE)
+    ///     .dst_factor_color(openxr::BlendFactorFB::ONE_MINUS_SRC_ALPHA)
+    ///     .src_factor_alpha(openxr::BlendFactorFB::ONE)
+    ///     .dst_factor_alpha(openxr::BlendFactorFB::ONE_MINUS_SRC_ALPHA);
+    /// let settings = openxr::CompositionLayerSettingsFB::new()
+    ///     .layer_flags(openxr::CompositionLayerSettingsFlagsFB::NORMAL_SHARPENING)
+    ///     .alpha_blend_fb(&blend);
+    /// let quad = quad.settings_fb(&settings);
+    /// # }
+    /// ```
     #[inline]
     pub fn end(
         &mut self,
@@ -108,20 +174,98 @@ impl<G: Graphics> FrameStream<G> {
         layers: &[&CompositionLayerBase<'_, G>],
     ) -> Result<()> {
         assert!(layers.len() <= u32::max_value() as usize);
+        let dimming = self.take_local_dimming();
         let info = sys::FrameEndInfo {
             ty: sys::FrameEndInfo::TYPE,
-            next: ptr::null(),
+            next: dimming.as_ref().map_or(ptr::null(), |x| x as *const _ as _),
             display_time,
             environment_blend_mode,
             layer_count: layers.len() as u32,
             layers: layers.as_ptr() as _,
         };
         unsafe {
-            cvt((self.fp().end_frame)(self.session.as_raw(), &info))?;
+            cvt(
+                "xrEndFrame",
+                (self.fp().end_frame)(self.session.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
 
+    /// Like `end`, but also submit layers for secondary view configurations
+    ///
+    /// Each secondary view configuration in `secondary` must have been enabled by
+    /// `Session::begin_with_secondary`. Requires `XR_MSFT_secondary_view_configuration`.
+    #[inline]
+    pub fn end_secondary(
+        &mut self,
+        display_time: Time,
+        environment_blend_mode: EnvironmentBlendMode,
+        layers: &[&CompositionLayerBase<'_, G>],
+        secondary: &[SecondaryViewConfigurationLayers<'_, G>],
+    ) -> Result<()> {
+        let dimming = self.take_local_dimming();
+        if self
+            .session
+            .instance()
+            .exts()
+            .msft_secondary_view_configuration
+            .is_none()
+        {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        assert!(layers.len() <= u32::max_value() as usize);
+        assert!(secondary.len() <= u32::max_value() as usize);
+        let secondary_layers = secondary
+            .iter()
+            .map(|x| {
+                assert!(x.layers.len() <= u32::max_value() as usize);
+                sys::SecondaryViewConfigurationLayerInfoMSFT {
+                    ty: sys::SecondaryViewConfigurationLayerInfoMSFT::TYPE,
+                    next: ptr::null(),
+                    view_configuration_type: x.view_configuration_type,
+                    environment_blend_mode: x.environment_blend_mode,
+                    layer_count: x.layers.len() as u32,
+                    layers: x.layers.as_ptr() as _,
+                }
+            })
+            .collect::<Vec<_>>();
+        let secondary_info = sys::SecondaryViewConfigurationFrameEndInfoMSFT {
+            ty: sys::SecondaryViewConfigurationFrameEndInfoMSFT::TYPE,
+            next: dimming.as_ref().map_or(ptr::null(), |x| x as *const _ as _),
+            view_configuration_count: secondary_layers.len() as u32,
+            view_configuration_layers_info: secondary_layers.as_ptr(),
+        };
+        let info = sys::FrameEndInfo {
+            ty: sys::FrameEndInfo::TYPE,
+            next: &secondary_info as *const _ as _,
+            display_time,
+            environment_blend_mode,
+            layer_count: layers.len() as u32,
+            layers: layers.as_ptr() as _,
+        };
+        unsafe {
+            cvt(
+                "xrEndFrame",
+                (self.fp().end_frame)(self.session.as_raw(), &info),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Consume the pending local dimming request, returning the structure to chain if any
+    fn take_local_dimming(&mut self) -> Option<sys::LocalDimmingFrameEndInfoMETA> {
+        // Only set by `local_dimming` if the extension is enabled
+        if !mem::replace(&mut self.local_dimming, false) {
+            return None;
+        }
+        Some(sys::LocalDimmingFrameEndInfoMETA {
+            ty: sys::LocalDimmingFrameEndInfoMETA::TYPE,
+            next: ptr::null(),
+            local_dimming_mode: LocalDimmingModeMETA::ON,
+        })
+    }
+
     // Private helper
     #[inline]
     fn fp(&self) -> &raw::Instance {
@@ -132,6 +276,125 @@ impl<G: Graphics> FrameStream<G> {
 #[derive(Debug, Copy, Clone)]
 pub struct FrameState {
     pub predicted_display_time: Time,
+    /// The period reported by the runtime, which may be zero or negative on early frames
     pub predicted_display_period: Duration,
     pub should_render: bool,
+    /// Most recent positive period reported to the same `FrameWaiter`, including this frame's
+    display_period: Option<Duration>,
+}
+
+/// Stand-in for the display period before the runtime has reported a valid one, i.e. 90Hz
+pub const DEFAULT_DISPLAY_PERIOD: Duration = Duration::from_nanos(11_111_111);
+
+impl FrameState {
+    /// Convert `raw`, tracking the most recent positive display period in `last`
+    pub(crate) fn new(raw: &sys::FrameState, last: &mut Option<Duration>) -> Self {
+        if raw.predicted_display_period.as_nanos() > 0 {
+            *last = Some(raw.predicted_display_period);
+        }
+        Self {
+            predicted_display_time: raw.predicted_display_time,
+            predicted_display_period: raw.predicted_display_period,
+            should_render: raw.should_render.into(),
+            display_period: *last,
+        }
+    }
+
+    /// `predicted_display_period` if positive, otherwise the last positive period reported to the
+    /// same `FrameWaiter`, or `DEFAULT_DISPLAY_PERIOD` if there has been none
+    ///
+    /// Always positive, so safe to use in frame pacing calculations.
+    #[inline]
+    pub fn display_period(&self) -> Duration {
+        self.display_period.unwrap_or(DEFAULT_DISPLAY_PERIOD)
+    }
+
+    /// `predicted_display_period` as a `std::time::Duration`, or zero if it isn't positive
+    #[inline]
+    pub fn predicted_display_period_duration(&self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.predicted_display_period.as_nanos().max(0) as u64)
+    }
+
+    /// Time from `other` until `predicted_display_time`, negative if `other` is later
+    #[inline]
+    pub fn predicted_display_time_since(&self, other: Time) -> Duration {
+        self.predicted_display_time - other
+    }
+}
+
+/// Whether a secondary view configuration should be rendered this frame, as reported by
+/// `FrameWaiter::wait_secondary`
+#[derive(Debug, Copy, Clone)]
+pub struct SecondaryViewConfigurationState {
+    pub view_configuration_type: ViewConfigurationType,
+    /// Whether layers should be submitted for this view configuration
+    pub active: bool,
+}
+
+/// Layers to submit for a secondary view configuration with `FrameStream::end_secondary`
+pub struct SecondaryViewConfigurationLayers<'a, G: Graphics> {
+    pub view_configuration_type: ViewConfigurationType,
+    pub environment_blend_mode: EnvironmentBlendMode,
+    /// Composition layers in back-to-front order, as in `FrameStream::end`
+    pub layers: &'a [&'a CompositionLayerBase<'a, G>],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(predicted_display_period: Duration) -> sys::FrameState {
+        sys::FrameState {
+            ty: sys::FrameState::TYPE,
+            next: ptr::null_mut(),
+            predicted_display_time: Time::from_nanos(0),
+            predicted_display_period,
+            should_render: true.into(),
+        }
+    }
+
+    #[test]
+    fn display_period_defaults_before_first_positive() {
+        let mut last = None;
+        let state = FrameState::new(&raw(Duration::from_nanos(0)), &mut last);
+        assert_eq!(state.display_period(), DEFAULT_DISPLAY_PERIOD);
+        assert_eq!(state.predicted_display_period, Duration::from_nanos(0));
+        assert_eq!(last, None);
+    }
+
+    #[test]
+    fn display_period_keeps_last_positive() {
+        let period = Duration::from_nanos(13_888_889);
+        let mut last = None;
+        let state = FrameState::new(&raw(period), &mut last);
+        assert_eq!(state.display_period(), period);
+        let state = FrameState::new(&raw(Duration::from_nanos(0)), &mut last);
+        assert_eq!(state.display_period(), period);
+    }
+
+    #[test]
+    fn display_period_ignores_negative() {
+        let mut last = None;
+        let state = FrameState::new(&raw(Duration::from_nanos(-1)), &mut last);
+        assert_eq!(state.display_period(), DEFAULT_DISPLAY_PERIOD);
+        let period = Duration::from_nanos(8_333_333);
+        FrameState::new(&raw(period), &mut last);
+        let state = FrameState::new(&raw(Duration::from_nanos(-1)), &mut last);
+        assert_eq!(state.display_period(), period);
+    }
+
+    #[test]
+    fn predicted_display_period_duration_clamps_non_positive() {
+        let mut last = None;
+        let state = FrameState::new(&raw(Duration::from_nanos(-5)), &mut last);
+        assert_eq!(
+            state.predicted_display_period_duration(),
+            std::time::Duration::from_nanos(0)
+        );
+        let state = FrameState::new(&raw(Duration::from_nanos(11_111_111)), &mut last);
+        assert_eq!(
+            state.predicted_display_period_duration(),
+            std::time::Duration::from_nanos(11_111_111)
+        );
+    }
 }
@@ -17,8 +17,8 @@ impl Graphics for D3D11 {
     type Format = u32;
     type SwapchainImage = *mut ID3D11Texture2D;
 
-    fn raise_format(x: i64) -> u32 {
-        x as _
+    fn raise_format(x: i64) -> Option<u32> {
+        Some(x as _)
     }
     fn lower_format(x: u32) -> i64 {
         x.into()
@@ -27,11 +27,14 @@ impl Graphics for D3D11 {
     fn requirements(inst: &Instance, system: SystemId) -> Result<Requirements> {
         let out = unsafe {
             let mut x = sys::GraphicsRequirementsD3D11KHR::out(ptr::null_mut());
-            cvt((inst.d3d11().get_d3d11_graphics_requirements)(
-                inst.as_raw(),
-                system,
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetD3D11GraphicsRequirementsKHR",
+                (inst.d3d11()?.get_d3d11_graphics_requirements)(
+                    inst.as_raw(),
+                    system,
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
         Ok(Requirements {
@@ -44,10 +47,11 @@ impl Graphics for D3D11 {
         instance: &Instance,
         system: SystemId,
         info: &Self::SessionCreateInfo,
+        next: *const std::ffi::c_void,
     ) -> Result<sys::Session> {
         let binding = sys::GraphicsBindingD3D11KHR {
             ty: sys::GraphicsBindingD3D11KHR::TYPE,
-            next: ptr::null(),
+            next,
             device: info.device,
         };
         let info = sys::SessionCreateInfo {
@@ -57,11 +61,10 @@ impl Graphics for D3D11 {
             system_id: system,
         };
         let mut out = sys::Session::NULL;
-        cvt((instance.fp().create_session)(
-            instance.as_raw(),
-            &info,
-            &mut out,
-        ))?;
+        cvt(
+            "xrCreateSession",
+            (instance.fp().create_session)(instance.as_raw(), &info, &mut out),
+        )?;
         Ok(out)
     }
 
@@ -69,6 +72,7 @@ impl Graphics for D3D11 {
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
         let images = get_arr_init(
+            "xrEnumerateSwapchainImages",
             sys::SwapchainImageD3D11KHR {
                 ty: sys::SwapchainImageD3D11KHR::TYPE,
                 next: ptr::null_mut(),
@@ -87,13 +91,60 @@ impl Graphics for D3D11 {
     }
 }
 
+/// The adapter and minimum feature level the runtime requires for a particular system
 #[derive(Copy, Clone)]
 pub struct Requirements {
     pub adapter_luid: LUID,
     pub min_feature_level: D3D_FEATURE_LEVEL,
 }
 
+/// D3D11 device a session will render with
+///
+/// `device` must have been created on the adapter identified by `Requirements::adapter_luid`.
 #[derive(Copy, Clone)]
 pub struct SessionCreateInfo {
     pub device: *mut ID3D11Device,
 }
+
+/// Whether the `DXGI_FORMAT` `format` stores color in the sRGB encoding, i.e. is gamma corrected
+/// on write
+///
+/// Only formats commonly used for swapchains are recognized. Applies to both D3D11 and D3D12.
+// `matches!` requires Rust 1.42
+#[allow(clippy::match_like_matches_macro)]
+pub fn format_is_srgb(format: u32) -> bool {
+    match format {
+        29 | 91 | 93 => true,
+        _ => false,
+    }
+}
+
+/// The name of the `DXGI_FORMAT` `format` as defined by the DXGI headers, e.g.
+/// `DXGI_FORMAT_R8G8B8A8_UNORM_SRGB`
+///
+/// Only formats commonly used for swapchains are recognized. Applies to both D3D11 and D3D12.
+pub fn format_name(format: u32) -> Option<&'static str> {
+    FORMAT_NAMES
+        .iter()
+        .find(|&&(x, _)| x == format)
+        .map(|&(_, name)| name)
+}
+
+const FORMAT_NAMES: &[(u32, &str)] = &[
+    (2, "DXGI_FORMAT_R32G32B32A32_FLOAT"),
+    (10, "DXGI_FORMAT_R16G16B16A16_FLOAT"),
+    (11, "DXGI_FORMAT_R16G16B16A16_UNORM"),
+    (20, "DXGI_FORMAT_D32_FLOAT_S8X24_UINT"),
+    (24, "DXGI_FORMAT_R10G10B10A2_UNORM"),
+    (26, "DXGI_FORMAT_R11G11B10_FLOAT"),
+    (28, "DXGI_FORMAT_R8G8B8A8_UNORM"),
+    (29, "DXGI_FORMAT_R8G8B8A8_UNORM_SRGB"),
+    (40, "DXGI_FORMAT_D32_FLOAT"),
+    (45, "DXGI_FORMAT_D24_UNORM_S8_UINT"),
+    (55, "DXGI_FORMAT_D16_UNORM"),
+    (85, "DXGI_FORMAT_B5G6R5_UNORM"),
+    (87, "DXGI_FORMAT_B8G8R8A8_UNORM"),
+    (88, "DXGI_FORMAT_B8G8R8X8_UNORM"),
+    (91, "DXGI_FORMAT_B8G8R8A8_UNORM_SRGB"),
+    (93, "DXGI_FORMAT_B8G8R8X8_UNORM_SRGB"),
+];
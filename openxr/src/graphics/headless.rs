@@ -0,0 +1,59 @@
+use std::convert::Infallible;
+
+use crate::*;
+
+/// No graphics API, for sessions that never submit rendered frames
+///
+/// Requires [`XR_MND_headless`]. Headless sessions support the session lifecycle, input, and
+/// spaces, and may drive the frame loop by submitting frames with no layers, but cannot create
+/// swapchains.
+///
+/// [`XR_MND_headless`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_MND_headless
+pub enum Headless {}
+
+impl Graphics for Headless {
+    type Requirements = ();
+    type SessionCreateInfo = ();
+    type Format = Infallible;
+    type SwapchainImage = Infallible;
+
+    fn raise_format(_: i64) -> Option<Infallible> {
+        // Headless sessions have no swapchain formats, so ignore any a runtime reports
+        None
+    }
+    fn lower_format(x: Infallible) -> i64 {
+        match x {}
+    }
+
+    fn requirements(_: &Instance, _: SystemId) -> Result<()> {
+        Ok(())
+    }
+
+    unsafe fn create_session(
+        instance: &Instance,
+        system: SystemId,
+        _: &(),
+        next: *const std::ffi::c_void,
+    ) -> Result<sys::Session> {
+        if instance.exts().mnd_headless.is_none() {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        let info = sys::SessionCreateInfo {
+            ty: sys::SessionCreateInfo::TYPE,
+            next,
+            create_flags: Default::default(),
+            system_id: system,
+        };
+        let mut out = sys::Session::NULL;
+        cvt(
+            "xrCreateSession",
+            (instance.fp().create_session)(instance.as_raw(), &info, &mut out),
+        )?;
+        Ok(out)
+    }
+
+    fn enumerate_swapchain_images(_: &Swapchain<Self>) -> Result<Vec<Infallible>> {
+        // Headless sessions cannot create swapchains, so there are never any images
+        Ok(Vec::new())
+    }
+}
@@ -14,19 +14,22 @@ pub trait Graphics: Sized {
     /// Identifiers for images to render to
     type SwapchainImage;
 
+    /// `None` if `x` can't be represented, in which case it's omitted from results
     #[doc(hidden)]
-    fn raise_format(x: i64) -> Self::Format;
+    fn raise_format(x: i64) -> Option<Self::Format>;
     #[doc(hidden)]
     fn lower_format(x: Self::Format) -> i64;
 
     #[doc(hidden)]
     fn requirements(instance: &Instance, system: SystemId) -> Result<Self::Requirements>;
 
+    /// `next` is chained onto the graphics binding
     #[doc(hidden)]
     unsafe fn create_session(
         instance: &Instance,
         system: SystemId,
         info: &Self::SessionCreateInfo,
+        next: *const std::ffi::c_void,
     ) -> Result<sys::Session>;
 
     #[doc(hidden)]
@@ -39,8 +42,21 @@ pub mod d3d;
 #[cfg(windows)]
 pub use d3d::D3D11;
 
+#[cfg(windows)]
+pub mod d3d12;
+#[cfg(windows)]
+pub use d3d12::D3D12;
+
 pub mod vulkan;
 pub use vulkan::Vulkan;
 
 pub mod opengl;
 pub use opengl::OpenGL;
+
+pub mod headless;
+pub use headless::Headless;
+
+#[cfg(all(target_os = "android", feature = "opengles"))]
+pub mod opengles;
+#[cfg(all(target_os = "android", feature = "opengles"))]
+pub use opengles::OpenGlEs;
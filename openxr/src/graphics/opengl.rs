@@ -17,8 +17,8 @@ impl Graphics for OpenGL {
     type Format = u32;
     type SwapchainImage = u32;
 
-    fn raise_format(x: i64) -> u32 {
-        x as _
+    fn raise_format(x: i64) -> Option<u32> {
+        Some(x as _)
     }
     fn lower_format(x: u32) -> i64 {
         x.into()
@@ -27,11 +27,14 @@ impl Graphics for OpenGL {
     fn requirements(inst: &Instance, system: SystemId) -> Result<Requirements> {
         let out = unsafe {
             let mut x = sys::GraphicsRequirementsOpenGLKHR::out(ptr::null_mut());
-            cvt((inst.opengl().get_open_gl_graphics_requirements)(
-                inst.as_raw(),
-                system,
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetOpenGLGraphicsRequirementsKHR",
+                (inst.opengl()?.get_open_gl_graphics_requirements)(
+                    inst.as_raw(),
+                    system,
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
         Ok(Requirements {
@@ -44,29 +47,18 @@ impl Graphics for OpenGL {
         instance: &Instance,
         system: SystemId,
         info: &Self::SessionCreateInfo,
+        next: *const std::ffi::c_void,
     ) -> Result<sys::Session> {
         match *info {
             #[cfg(windows)]
             SessionCreateInfo::Windows { h_dc, h_glrc } => {
                 let binding = sys::GraphicsBindingOpenGLWin32KHR {
                     ty: sys::GraphicsBindingOpenGLWin32KHR::TYPE,
-                    next: ptr::null(),
+                    next,
                     h_dc,
                     h_glrc,
                 };
-                let info = sys::SessionCreateInfo {
-                    ty: sys::SessionCreateInfo::TYPE,
-                    next: &binding as *const _ as *const _,
-                    create_flags: Default::default(),
-                    system_id: system,
-                };
-                let mut out = sys::Session::NULL;
-                cvt((instance.fp().create_session)(
-                    instance.as_raw(),
-                    &info,
-                    &mut out,
-                ))?;
-                Ok(out)
+                create_session_with(instance, system, &binding as *const _ as *const _)
             }
             SessionCreateInfo::Xlib {
                 x_display,
@@ -77,26 +69,44 @@ impl Graphics for OpenGL {
             } => {
                 let binding = sys::GraphicsBindingOpenGLXlibKHR {
                     ty: sys::GraphicsBindingOpenGLXlibKHR::TYPE,
-                    next: ptr::null(),
+                    next,
                     x_display,
                     visualid,
                     glx_fb_config,
                     glx_drawable,
                     glx_context,
                 };
-                let info = sys::SessionCreateInfo {
-                    ty: sys::SessionCreateInfo::TYPE,
-                    next: &binding as *const _ as *const _,
-                    create_flags: Default::default(),
-                    system_id: system,
+                create_session_with(instance, system, &binding as *const _ as *const _)
+            }
+            #[cfg(all(unix, not(target_os = "android")))]
+            SessionCreateInfo::Xcb {
+                connection,
+                screen_number,
+                fbconfigid,
+                visualid,
+                glx_drawable,
+                glx_context,
+            } => {
+                let binding = sys::GraphicsBindingOpenGLXcbKHR {
+                    ty: sys::GraphicsBindingOpenGLXcbKHR::TYPE,
+                    next,
+                    connection,
+                    screen_number,
+                    fbconfigid,
+                    visualid,
+                    glx_drawable,
+                    glx_context,
+                };
+                create_session_with(instance, system, &binding as *const _ as *const _)
+            }
+            #[cfg(all(unix, not(target_os = "android")))]
+            SessionCreateInfo::Wayland { display } => {
+                let binding = sys::GraphicsBindingOpenGLWaylandKHR {
+                    ty: sys::GraphicsBindingOpenGLWaylandKHR::TYPE,
+                    next,
+                    display,
                 };
-                let mut out = sys::Session::NULL;
-                cvt((instance.fp().create_session)(
-                    instance.as_raw(),
-                    &info,
-                    &mut out,
-                ))?;
-                Ok(out)
+                create_session_with(instance, system, &binding as *const _ as *const _)
             }
         }
     }
@@ -105,6 +115,7 @@ impl Graphics for OpenGL {
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
         let images = get_arr_init(
+            "xrEnumerateSwapchainImages",
             sys::SwapchainImageOpenGLKHR {
                 ty: sys::SwapchainImageOpenGLKHR::TYPE,
                 next: ptr::null_mut(),
@@ -123,12 +134,33 @@ impl Graphics for OpenGL {
     }
 }
 
-#[derive(Copy, Clone)]
+unsafe fn create_session_with(
+    instance: &Instance,
+    system: SystemId,
+    binding: *const std::ffi::c_void,
+) -> Result<sys::Session> {
+    let info = sys::SessionCreateInfo {
+        ty: sys::SessionCreateInfo::TYPE,
+        next: binding,
+        create_flags: Default::default(),
+        system_id: system,
+    };
+    let mut out = sys::Session::NULL;
+    cvt(
+        "xrCreateSession",
+        (instance.fp().create_session)(instance.as_raw(), &info, &mut out),
+    )?;
+    Ok(out)
+}
+
+/// OpenGL API versions supported by the runtime for a particular system
+#[derive(Debug, Copy, Clone)]
 pub struct Requirements {
     pub min_api_version_supported: Version,
     pub max_api_version_supported: Version,
 }
 
+/// Platform-specific handles identifying the OpenGL context a session will render with
 pub enum SessionCreateInfo {
     Xlib {
         x_display: *mut Display,
@@ -137,6 +169,60 @@ pub enum SessionCreateInfo {
         glx_drawable: GLXDrawable,
         glx_context: GLXContext,
     },
+    #[cfg(all(unix, not(target_os = "android")))]
+    Xcb {
+        connection: *mut xcb_connection_t,
+        screen_number: u32,
+        fbconfigid: xcb_glx_fbconfig_t,
+        visualid: xcb_visualid_t,
+        glx_drawable: xcb_glx_drawable_t,
+        glx_context: xcb_glx_context_t,
+    },
+    #[cfg(all(unix, not(target_os = "android")))]
+    Wayland { display: *mut wl_display },
     #[cfg(windows)]
     Windows { h_dc: HDC, h_glrc: HGLRC },
 }
+
+/// Whether the internal format `format` stores color in the sRGB encoding, i.e. is gamma
+/// corrected on write
+///
+/// Only formats commonly used for swapchains are recognized. Also applies to OpenGL ES.
+// `matches!` requires Rust 1.42
+#[allow(clippy::match_like_matches_macro)]
+pub fn format_is_srgb(format: u32) -> bool {
+    match format {
+        0x8C41 | 0x8C43 => true,
+        _ => false,
+    }
+}
+
+/// The name of the internal format `format` as defined by the OpenGL headers, e.g.
+/// `GL_SRGB8_ALPHA8`
+///
+/// Only formats commonly used for swapchains are recognized. Also applies to OpenGL ES.
+pub fn format_name(format: u32) -> Option<&'static str> {
+    FORMAT_NAMES
+        .iter()
+        .find(|&&(x, _)| x == format)
+        .map(|&(_, name)| name)
+}
+
+const FORMAT_NAMES: &[(u32, &str)] = &[
+    (0x8051, "GL_RGB8"),
+    (0x8058, "GL_RGBA8"),
+    (0x8059, "GL_RGB10_A2"),
+    (0x805B, "GL_RGBA16"),
+    (0x81A5, "GL_DEPTH_COMPONENT16"),
+    (0x81A6, "GL_DEPTH_COMPONENT24"),
+    (0x8814, "GL_RGBA32F"),
+    (0x881A, "GL_RGBA16F"),
+    (0x881B, "GL_RGB16F"),
+    (0x88F0, "GL_DEPTH24_STENCIL8"),
+    (0x8C3A, "GL_R11F_G11F_B10F"),
+    (0x8C41, "GL_SRGB8"),
+    (0x8C43, "GL_SRGB8_ALPHA8"),
+    (0x8CAC, "GL_DEPTH_COMPONENT32F"),
+    (0x8CAD, "GL_DEPTH32F_STENCIL8"),
+    (0x8D62, "GL_RGB565"),
+];
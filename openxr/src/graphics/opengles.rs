@@ -0,0 +1,120 @@
+use std::ptr;
+
+use sys::platform::*;
+
+use crate::*;
+
+/// The OpenGL ES graphics API
+///
+/// See [`XR_KHR_opengl_es_enable`] for safety details.
+///
+/// [`XR_KHR_opengl_es_enable`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_opengl_es_enable
+pub enum OpenGlEs {}
+
+impl Graphics for OpenGlEs {
+    type Requirements = Requirements;
+    type SessionCreateInfo = SessionCreateInfo;
+    type Format = u32;
+    type SwapchainImage = u32;
+
+    fn raise_format(x: i64) -> Option<u32> {
+        Some(x as _)
+    }
+    fn lower_format(x: u32) -> i64 {
+        x.into()
+    }
+
+    fn requirements(inst: &Instance, system: SystemId) -> Result<Requirements> {
+        let out = unsafe {
+            let mut x = sys::GraphicsRequirementsOpenGLESKHR::out(ptr::null_mut());
+            cvt(
+                "xrGetOpenGLESGraphicsRequirementsKHR",
+                (inst.opengl_es()?.get_open_gles_graphics_requirements)(
+                    inst.as_raw(),
+                    system,
+                    x.as_mut_ptr(),
+                ),
+            )?;
+            x.assume_init()
+        };
+        Ok(Requirements {
+            min_api_version_supported: out.min_api_version_supported,
+            max_api_version_supported: out.max_api_version_supported,
+        })
+    }
+
+    unsafe fn create_session(
+        instance: &Instance,
+        system: SystemId,
+        info: &Self::SessionCreateInfo,
+        next: *const std::ffi::c_void,
+    ) -> Result<sys::Session> {
+        match *info {
+            SessionCreateInfo::Android {
+                display,
+                config,
+                context,
+            } => {
+                let binding = sys::GraphicsBindingOpenGLESAndroidKHR {
+                    ty: sys::GraphicsBindingOpenGLESAndroidKHR::TYPE,
+                    next,
+                    display,
+                    config,
+                    context,
+                };
+                let info = sys::SessionCreateInfo {
+                    ty: sys::SessionCreateInfo::TYPE,
+                    next: &binding as *const _ as *const _,
+                    create_flags: Default::default(),
+                    system_id: system,
+                };
+                let mut out = sys::Session::NULL;
+                cvt(
+                    "xrCreateSession",
+                    (instance.fp().create_session)(instance.as_raw(), &info, &mut out),
+                )?;
+                Ok(out)
+            }
+        }
+    }
+
+    fn enumerate_swapchain_images(
+        swapchain: &Swapchain<Self>,
+    ) -> Result<Vec<Self::SwapchainImage>> {
+        let images = get_arr_init(
+            "xrEnumerateSwapchainImages",
+            sys::SwapchainImageOpenGLESKHR {
+                ty: sys::SwapchainImageOpenGLESKHR::TYPE,
+                next: ptr::null_mut(),
+                image: 0,
+            },
+            |capacity, count, buf| unsafe {
+                (swapchain.instance().fp().enumerate_swapchain_images)(
+                    swapchain.as_raw(),
+                    capacity,
+                    count,
+                    buf as *mut _,
+                )
+            },
+        )?;
+        Ok(images.into_iter().map(|x| x.image).collect())
+    }
+}
+
+/// OpenGL ES API versions supported by the runtime for a particular system
+#[derive(Debug, Copy, Clone)]
+pub struct Requirements {
+    pub min_api_version_supported: Version,
+    pub max_api_version_supported: Version,
+}
+
+/// Platform-specific handles identifying the OpenGL ES context a session will render with
+pub enum SessionCreateInfo {
+    Android {
+        display: EGLDisplay,
+        config: EGLConfig,
+        context: EGLContext,
+    },
+}
+
+pub use super::opengl::{format_is_srgb, format_name};
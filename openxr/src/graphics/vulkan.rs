@@ -1,4 +1,4 @@
-use std::ptr;
+use std::{ffi::CString, ptr};
 
 use sys::platform::*;
 
@@ -6,9 +6,12 @@ use crate::*;
 
 /// The Vulkan graphics API
 ///
-/// See [`XR_KHR_vulkan_enable`] for safety details.
+/// See [`XR_KHR_vulkan_enable`] for safety details. Either that or [`XR_KHR_vulkan_enable2`] may be
+/// enabled; with the latter, the Vulkan instance and device should be created with
+/// `Instance::create_vulkan_instance` and `Instance::create_vulkan_device`.
 ///
 /// [`XR_KHR_vulkan_enable`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_vulkan_enable
+/// [`XR_KHR_vulkan_enable2`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#XR_KHR_vulkan_enable2
 pub enum Vulkan {}
 
 impl Graphics for Vulkan {
@@ -17,8 +20,8 @@ impl Graphics for Vulkan {
     type SessionCreateInfo = SessionCreateInfo;
     type SwapchainImage = VkImage;
 
-    fn raise_format(x: i64) -> Self::Format {
-        x as _
+    fn raise_format(x: i64) -> Option<Self::Format> {
+        Some(x as _)
     }
     fn lower_format(x: Self::Format) -> i64 {
         x as _
@@ -27,11 +30,25 @@ impl Graphics for Vulkan {
     fn requirements(instance: &Instance, system: SystemId) -> Result<Requirements> {
         let out = unsafe {
             let mut x = sys::GraphicsRequirementsVulkanKHR::out(ptr::null_mut());
-            cvt((instance.vulkan().get_vulkan_graphics_requirements)(
-                instance.as_raw(),
-                system,
-                x.as_mut_ptr(),
-            ))?;
+            if instance.exts().khr_vulkan_enable.is_some() {
+                cvt(
+                    "xrGetVulkanGraphicsRequirementsKHR",
+                    (instance.vulkan()?.get_vulkan_graphics_requirements)(
+                        instance.as_raw(),
+                        system,
+                        x.as_mut_ptr(),
+                    ),
+                )?;
+            } else {
+                cvt(
+                    "xrGetVulkanGraphicsRequirements2KHR",
+                    (instance.vulkan2()?.get_vulkan_graphics_requirements2)(
+                        instance.as_raw(),
+                        system,
+                        x.as_mut_ptr(),
+                    ),
+                )?;
+            }
             x.assume_init()
         };
         Ok(Requirements {
@@ -44,10 +61,11 @@ impl Graphics for Vulkan {
         instance: &Instance,
         system: SystemId,
         info: &Self::SessionCreateInfo,
+        next: *const std::ffi::c_void,
     ) -> Result<sys::Session> {
         let binding = sys::GraphicsBindingVulkanKHR {
             ty: sys::GraphicsBindingVulkanKHR::TYPE,
-            next: ptr::null(),
+            next,
             instance: info.instance,
             physical_device: info.physical_device,
             device: info.device,
@@ -61,11 +79,10 @@ impl Graphics for Vulkan {
             system_id: system,
         };
         let mut out = sys::Session::NULL;
-        cvt((instance.fp().create_session)(
-            instance.as_raw(),
-            &info,
-            &mut out,
-        ))?;
+        cvt(
+            "xrCreateSession",
+            (instance.fp().create_session)(instance.as_raw(), &info, &mut out),
+        )?;
         Ok(out)
     }
 
@@ -73,6 +90,7 @@ impl Graphics for Vulkan {
         swapchain: &Swapchain<Self>,
     ) -> Result<Vec<Self::SwapchainImage>> {
         let images = get_arr_init(
+            "xrEnumerateSwapchainImages",
             sys::SwapchainImageVulkanKHR {
                 ty: sys::SwapchainImageVulkanKHR::TYPE,
                 next: ptr::null_mut(),
@@ -91,17 +109,121 @@ impl Graphics for Vulkan {
     }
 }
 
-#[derive(Copy, Clone)]
+/// Vulkan API versions supported by the runtime for a particular system
+#[derive(Debug, Copy, Clone)]
 pub struct Requirements {
     pub min_api_version_supported: Version,
     pub max_api_version_supported: Version,
 }
 
+/// Vulkan handles identifying the device and queue a session will render with
+///
+/// `physical_device` must be the device identified by `get_physical_device`.
+///
+/// The `openxr-wgpu` crate takes these from a `wgpu-hal` Vulkan device with
+/// `SessionCreateInfoExt::from_wgpu_hal`.
 #[derive(Copy, Clone)]
 pub struct SessionCreateInfo {
     pub instance: VkInstance,
     pub physical_device: VkPhysicalDevice,
     pub device: VkDevice,
+    /// Family of the queue the runtime will submit work to
     pub queue_family_index: u32,
+    /// Index of the queue within `queue_family_index`
     pub queue_index: u32,
 }
+
+/// Identify the physical device which must back sessions created for `system`
+///
+/// Uses whichever of `XR_KHR_vulkan_enable` and `XR_KHR_vulkan_enable2` is enabled. On systems
+/// with several GPUs, creating a session with any other device fails. The handle may be passed to
+/// other Vulkan bindings with e.g. `ash::vk::PhysicalDevice::from_raw(device as u64)`.
+///
+/// # Safety
+///
+/// `vk_instance` must be a valid Vulkan instance handle.
+pub unsafe fn get_physical_device(
+    instance: &Instance,
+    system: SystemId,
+    vk_instance: VkInstance,
+) -> Result<VkPhysicalDevice> {
+    if instance.exts().khr_vulkan_enable.is_some() {
+        instance.vulkan_graphics_device(system, vk_instance)
+    } else {
+        instance.vulkan_graphics_device2(system, vk_instance)
+    }
+}
+
+/// The Vulkan instance extensions which must be enabled for use with `system`
+///
+/// Requires `XR_KHR_vulkan_enable`. The names may be passed directly to `VkInstanceCreateInfo`.
+pub fn instance_extensions(instance: &Instance, system: SystemId) -> Result<Vec<CString>> {
+    Ok(split_extensions(
+        &instance.vulkan_instance_extensions(system)?,
+    ))
+}
+
+/// The Vulkan device extensions which must be enabled for use with `system`
+///
+/// Requires `XR_KHR_vulkan_enable`. The names may be passed directly to `VkDeviceCreateInfo`.
+pub fn device_extensions(instance: &Instance, system: SystemId) -> Result<Vec<CString>> {
+    Ok(split_extensions(
+        &instance.vulkan_device_extensions(system)?,
+    ))
+}
+
+fn split_extensions(list: &str) -> Vec<CString> {
+    list.split_ascii_whitespace()
+        .map(|x| CString::new(x).unwrap())
+        .collect()
+}
+
+/// Whether `format` stores color in the sRGB encoding, i.e. is gamma corrected on write
+///
+/// Only formats commonly used for swapchains are recognized.
+// `matches!` requires Rust 1.42
+#[allow(clippy::match_like_matches_macro)]
+pub fn format_is_srgb(format: VkFormat) -> bool {
+    match format {
+        29 | 36 | 43 | 50 | 57 => true,
+        _ => false,
+    }
+}
+
+/// The name of `format` as defined by the Vulkan headers, e.g. `VK_FORMAT_R8G8B8A8_SRGB`
+///
+/// Only formats commonly used for swapchains are recognized.
+pub fn format_name(format: VkFormat) -> Option<&'static str> {
+    FORMAT_NAMES
+        .iter()
+        .find(|&&(x, _)| x == format)
+        .map(|&(_, name)| name)
+}
+
+const FORMAT_NAMES: &[(VkFormat, &str)] = &[
+    (4, "VK_FORMAT_R5G6B5_UNORM_PACK16"),
+    (23, "VK_FORMAT_R8G8B8_UNORM"),
+    (29, "VK_FORMAT_R8G8B8_SRGB"),
+    (30, "VK_FORMAT_B8G8R8_UNORM"),
+    (36, "VK_FORMAT_B8G8R8_SRGB"),
+    (37, "VK_FORMAT_R8G8B8A8_UNORM"),
+    (43, "VK_FORMAT_R8G8B8A8_SRGB"),
+    (44, "VK_FORMAT_B8G8R8A8_UNORM"),
+    (50, "VK_FORMAT_B8G8R8A8_SRGB"),
+    (51, "VK_FORMAT_A8B8G8R8_UNORM_PACK32"),
+    (57, "VK_FORMAT_A8B8G8R8_SRGB_PACK32"),
+    (58, "VK_FORMAT_A2R10G10B10_UNORM_PACK32"),
+    (64, "VK_FORMAT_A2B10G10R10_UNORM_PACK32"),
+    (90, "VK_FORMAT_R16G16B16_SFLOAT"),
+    (91, "VK_FORMAT_R16G16B16A16_UNORM"),
+    (97, "VK_FORMAT_R16G16B16A16_SFLOAT"),
+    (109, "VK_FORMAT_R32G32B32A32_SFLOAT"),
+    (122, "VK_FORMAT_B10G11R11_UFLOAT_PACK32"),
+    (124, "VK_FORMAT_D16_UNORM"),
+    (125, "VK_FORMAT_X8_D24_UNORM_PACK32"),
+    (126, "VK_FORMAT_D32_SFLOAT"),
+    (127, "VK_FORMAT_S8_UINT"),
+    (128, "VK_FORMAT_D16_UNORM_S8_UINT"),
+    (129, "VK_FORMAT_D24_UNORM_S8_UINT"),
+    (130, "VK_FORMAT_D32_SFLOAT_S8_UINT"),
+];
@@ -0,0 +1,407 @@
+use std::{os::raw::c_void, ptr, sync::Arc};
+
+use crate::*;
+
+/// Number of joints reported for each hand by `HandTracker::locate`
+pub const HAND_JOINT_COUNT: usize = sys::HAND_JOINT_COUNT_EXT;
+
+/// Number of capsules reported for each hand by `HandTracker::locate_with_capsules`
+pub const HAND_TRACKING_CAPSULE_COUNT: usize = sys::HAND_TRACKING_CAPSULE_COUNT_FB;
+
+/// Tracks the joints of a single hand
+///
+/// Created by `Session::create_hand_tracker`. Requires `XR_EXT_hand_tracking`.
+pub struct HandTracker {
+    session: Arc<session::SessionInner>,
+    handle: sys::HandTrackerEXT,
+}
+
+impl HandTracker {
+    /// Take ownership of an existing hand tracker handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid hand tracker handle associated with `session`, which must have
+    /// been created with `XR_EXT_hand_tracking` enabled.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(session: Session<G>, handle: sys::HandTrackerEXT) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+        }
+    }
+
+    /// Access the raw hand tracker handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::HandTrackerEXT {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// Determine the location of each joint of the hand relative to `base` at `time`
+    ///
+    /// Velocities are only returned while the hand is actively tracked. Poses and velocities are
+    /// only meaningful when the corresponding bits of each joint's flags are set.
+    #[inline]
+    pub fn locate(
+        &self,
+        base: &Space,
+        time: Time,
+    ) -> Result<(HandJointLocations, Option<HandJointVelocities>)> {
+        self.locate_inner(base, time, ptr::null(), ptr::null_mut())
+    }
+
+    /// Like `locate`, but selecting how joints are posed while the hand holds a controller
+    ///
+    /// `locate` reports `HandJointsMotionRangeEXT::UNOBSTRUCTED`. Returns
+    /// `ERROR_EXTENSION_NOT_PRESENT` if `XR_EXT_hand_joints_motion_range` was not enabled on the
+    /// instance.
+    #[inline]
+    pub fn locate_with_motion_range(
+        &self,
+        base: &Space,
+        time: Time,
+        motion_range: HandJointsMotionRangeEXT,
+    ) -> Result<(HandJointLocations, Option<HandJointVelocities>)> {
+        if self
+            .instance()
+            .exts()
+            .ext_hand_joints_motion_range
+            .is_none()
+        {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        let range_info = sys::HandJointsMotionRangeInfoEXT {
+            ty: sys::HandJointsMotionRangeInfoEXT::TYPE,
+            next: ptr::null(),
+            hand_joints_motion_range: motion_range,
+        };
+        self.locate_inner(base, time, &range_info as *const _ as _, ptr::null_mut())
+    }
+
+    /// Like `locate`, but also reporting the hand's aim pose and pinch state
+    ///
+    /// Intended for controller-free UI interaction, e.g. pointing with `aim_pose` and clicking by
+    /// pinching. Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_hand_tracking_aim` was not enabled
+    /// on the instance.
+    pub fn locate_with_aim(
+        &self,
+        base: &Space,
+        time: Time,
+    ) -> Result<(
+        HandJointLocations,
+        Option<HandJointVelocities>,
+        HandTrackingAim,
+    )> {
+        if self.instance().exts().fb_hand_tracking_aim.is_none() {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        let mut aim = sys::HandTrackingAimStateFB {
+            ty: sys::HandTrackingAimStateFB::TYPE,
+            next: ptr::null_mut(),
+            status: HandTrackingAimFlagsFB::EMPTY,
+            aim_pose: Posef::IDENTITY,
+            pinch_strength_index: 0.0,
+            pinch_strength_middle: 0.0,
+            pinch_strength_ring: 0.0,
+            pinch_strength_little: 0.0,
+        };
+        let (locations, velocities) =
+            self.locate_inner(base, time, ptr::null(), &mut aim as *mut _ as _)?;
+        let aim = HandTrackingAim {
+            status: aim.status,
+            aim_pose: aim.aim_pose,
+            pinch_strength_index: aim.pinch_strength_index,
+            pinch_strength_middle: aim.pinch_strength_middle,
+            pinch_strength_ring: aim.pinch_strength_ring,
+            pinch_strength_little: aim.pinch_strength_little,
+        };
+        Ok((locations, velocities, aim))
+    }
+
+    /// Like `locate`, but also reporting capsules approximating the hand's volume, e.g. for
+    /// physics collision
+    ///
+    /// Capsule end points are relative to `base`, and only meaningful while the hand is active.
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_hand_tracking_capsules` was not enabled on
+    /// the instance.
+    pub fn locate_with_capsules(
+        &self,
+        base: &Space,
+        time: Time,
+    ) -> Result<(
+        HandJointLocations,
+        Option<HandJointVelocities>,
+        [sys::HandCapsuleFB; HAND_TRACKING_CAPSULE_COUNT],
+    )> {
+        if self.instance().exts().fb_hand_tracking_capsules.is_none() {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        const ZERO: Vector3f = Vector3f {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let mut capsules = sys::HandTrackingCapsulesStateFB {
+            ty: sys::HandTrackingCapsulesStateFB::TYPE,
+            next: ptr::null_mut(),
+            capsules: [sys::HandCapsuleFB {
+                points: [ZERO; sys::HAND_TRACKING_CAPSULE_POINT_COUNT_FB],
+                radius: 0.0,
+                joint: HandJointEXT::PALM,
+            }; HAND_TRACKING_CAPSULE_COUNT],
+        };
+        let (locations, velocities) =
+            self.locate_inner(base, time, ptr::null(), &mut capsules as *mut _ as _)?;
+        Ok((locations, velocities, capsules.capsules))
+    }
+
+    /// `next` extends the locate info, and `out_next` the joint locations after the velocities
+    fn locate_inner(
+        &self,
+        base: &Space,
+        time: Time,
+        next: *const c_void,
+        out_next: *mut c_void,
+    ) -> Result<(HandJointLocations, Option<HandJointVelocities>)> {
+        // This assert allows this function to be safe.
+        assert_eq!(&*self.session as *const session::SessionInner, &*base.session as *const session::SessionInner,
+                   "`self` and `base` must have been created, allocated, or retrieved from the same `Session`");
+        let info = sys::HandJointsLocateInfoEXT {
+            ty: sys::HandJointsLocateInfoEXT::TYPE,
+            next,
+            base_space: base.as_raw(),
+            time,
+        };
+        // Joint arrays are written in full by the runtime on success
+        let mut locations = HandJointLocations {
+            is_active: false,
+            joints: [sys::HandJointLocationEXT {
+                location_flags: SpaceLocationFlags::EMPTY,
+                pose: Posef::IDENTITY,
+                radius: 0.0,
+            }; HAND_JOINT_COUNT],
+        };
+        const ZERO: Vector3f = Vector3f {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        };
+        let mut velocities = HandJointVelocities {
+            joints: [sys::HandJointVelocityEXT {
+                velocity_flags: SpaceVelocityFlags::EMPTY,
+                linear_velocity: ZERO,
+                angular_velocity: ZERO,
+            }; HAND_JOINT_COUNT],
+        };
+        let mut raw_velocities = sys::HandJointVelocitiesEXT {
+            ty: sys::HandJointVelocitiesEXT::TYPE,
+            next: out_next,
+            joint_count: HAND_JOINT_COUNT as u32,
+            joint_velocities: velocities.joints.as_mut_ptr(),
+        };
+        let mut raw_locations = sys::HandJointLocationsEXT {
+            ty: sys::HandJointLocationsEXT::TYPE,
+            next: &mut raw_velocities as *mut _ as _,
+            is_active: false.into(),
+            joint_count: HAND_JOINT_COUNT as u32,
+            joint_locations: locations.joints.as_mut_ptr(),
+        };
+        unsafe {
+            cvt(
+                "xrLocateHandJointsEXT",
+                (self.fp().locate_hand_joints)(self.as_raw(), &info, &mut raw_locations),
+            )?;
+        }
+        locations.is_active = raw_locations.is_active.into();
+        let velocities = if locations.is_active {
+            Some(velocities)
+        } else {
+            None
+        };
+        Ok((locations, velocities))
+    }
+
+    /// Create a `Space` in which the hand mesh from `get_hand_mesh` with `pose_type` is defined,
+    /// offset by `pose`
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_MSFT_hand_tracking_mesh` was not enabled on the
+    /// instance.
+    pub fn create_hand_mesh_space(
+        &self,
+        pose_type: HandPoseTypeMSFT,
+        pose: Posef,
+    ) -> Result<Space> {
+        let info = sys::HandMeshSpaceCreateInfoMSFT {
+            ty: sys::HandMeshSpaceCreateInfoMSFT::TYPE,
+            next: ptr::null(),
+            hand_pose_type: pose_type,
+            pose_in_hand_mesh_space: pose,
+        };
+        let mut out = sys::Space::NULL;
+        unsafe {
+            cvt(
+                "xrCreateHandMeshSpaceMSFT",
+                (self.mesh_fp()?.create_hand_mesh_space)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(Space::from_inner(self.session.clone(), out))
+        }
+    }
+
+    /// Get the deformable mesh of the hand at `time`
+    ///
+    /// With `HandPoseTypeMSFT::TRACKED`, the mesh follows the user's hand, and is inactive while
+    /// the hand is not tracked. With `REFERENCE_OPEN_PALM`, it is the hand's neutral pose, e.g. for
+    /// computing skinning weights against the joints reported by `locate`. Returns
+    /// `ERROR_EXTENSION_NOT_PRESENT` if `XR_MSFT_hand_tracking_mesh` was not enabled on the
+    /// instance.
+    pub fn get_hand_mesh(&self, time: Time, pose_type: HandPoseTypeMSFT) -> Result<HandMesh> {
+        let fp = self.mesh_fp()?;
+        let info = sys::HandMeshUpdateInfoMSFT {
+            ty: sys::HandMeshUpdateInfoMSFT::TYPE,
+            next: ptr::null(),
+            time,
+            hand_pose_type: pose_type,
+        };
+        let mut out = HandMesh {
+            is_active: false,
+            vertex_update_time: Time::from_nanos(0),
+            vertices: Vec::with_capacity(1024),
+            indices: Vec::with_capacity(4096),
+        };
+        loop {
+            // A zero key and update time make the runtime write both buffers in full
+            let mut mesh = sys::HandMeshMSFT {
+                ty: sys::HandMeshMSFT::TYPE,
+                next: ptr::null_mut(),
+                is_active: false.into(),
+                index_buffer_changed: false.into(),
+                vertex_buffer_changed: false.into(),
+                index_buffer: sys::HandMeshIndexBufferMSFT {
+                    index_buffer_key: 0,
+                    index_capacity_input: out.indices.capacity() as u32,
+                    index_count_output: 0,
+                    indices: out.indices.as_mut_ptr(),
+                },
+                vertex_buffer: sys::HandMeshVertexBufferMSFT {
+                    vertex_update_time: Time::from_nanos(0),
+                    vertex_capacity_input: out.vertices.capacity() as u32,
+                    vertex_count_output: 0,
+                    vertices: out.vertices.as_mut_ptr(),
+                },
+            };
+            let status = unsafe { (fp.update_hand_mesh)(self.as_raw(), &info, &mut mesh) };
+            if status == sys::Result::ERROR_SIZE_INSUFFICIENT {
+                // Counts aren't reliably reported on failure, so grow geometrically
+                let vertices = (mesh.vertex_buffer.vertex_count_output as usize)
+                    .max(2 * out.vertices.capacity());
+                let indices =
+                    (mesh.index_buffer.index_count_output as usize).max(2 * out.indices.capacity());
+                out.vertices.reserve_exact(vertices);
+                out.indices.reserve_exact(indices);
+                continue;
+            }
+            cvt("xrUpdateHandMeshMSFT", status)?;
+            unsafe {
+                out.vertices
+                    .set_len(mesh.vertex_buffer.vertex_count_output as usize);
+                out.indices
+                    .set_len(mesh.index_buffer.index_count_output as usize);
+            }
+            out.is_active = mesh.is_active.into();
+            out.vertex_update_time = mesh.vertex_buffer.vertex_update_time;
+            return Ok(out);
+        }
+    }
+
+    // Private helper
+    #[inline]
+    fn mesh_fp(&self) -> Result<&raw::HandTrackingMeshMSFT> {
+        self.instance()
+            .exts()
+            .msft_hand_tracking_mesh
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+
+    // Private helper
+    #[inline]
+    fn fp(&self) -> &raw::HandTrackingEXT {
+        self.instance()
+            .exts()
+            .ext_hand_tracking
+            .as_ref()
+            .expect("EXT_hand_tracking not loaded")
+    }
+}
+
+impl Drop for HandTracker {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp().destroy_hand_tracker)(self.handle);
+        }
+    }
+}
+
+/// Locations of every joint of a hand, obtained from `HandTracker::locate`
+#[derive(Copy, Clone)]
+pub struct HandJointLocations {
+    /// Whether the hand is currently being tracked
+    ///
+    /// If false, no joint has any location flags set.
+    pub is_active: bool,
+    /// Pose, radius and location flags of each joint, indexed by `HandJointEXT`
+    pub joints: [HandJointLocationEXT; HAND_JOINT_COUNT],
+}
+
+/// Velocities of every joint of a hand, obtained from `HandTracker::locate`
+#[derive(Copy, Clone)]
+pub struct HandJointVelocities {
+    /// Linear and angular velocity and velocity flags of each joint, indexed by `HandJointEXT`
+    pub joints: [HandJointVelocityEXT; HAND_JOINT_COUNT],
+}
+
+/// Aim pose and pinch state of a hand, obtained from `HandTracker::locate_with_aim`
+#[derive(Copy, Clone)]
+pub struct HandTrackingAim {
+    /// Whether `aim_pose` is valid, which fingers are pinching, and whether the system or menu
+    /// gesture is active
+    pub status: HandTrackingAimFlagsFB,
+    /// Origin and direction, along -Z, of a ray for pointing at UI
+    ///
+    /// Only meaningful if `status` contains `VALID`.
+    pub aim_pose: Posef,
+    /// Strength of the pinch between the thumb and each finger, from 0.0 to 1.0
+    pub pinch_strength_index: f32,
+    pub pinch_strength_middle: f32,
+    pub pinch_strength_ring: f32,
+    pub pinch_strength_little: f32,
+}
+
+impl HandTrackingAim {
+    /// Whether `aim_pose` is valid
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.status.contains(HandTrackingAimFlagsFB::VALID)
+    }
+}
+
+/// Deformable mesh of a hand, obtained from `HandTracker::get_hand_mesh`
+#[derive(Clone)]
+pub struct HandMesh {
+    /// Whether the hand is currently being tracked
+    ///
+    /// If false, the mesh is stale and should not be displayed.
+    pub is_active: bool,
+    /// Time at which `vertices` were sampled
+    pub vertex_update_time: Time,
+    /// Position and normal of each vertex, in the hand mesh space
+    pub vertices: Vec<HandMeshVertexMSFT>,
+    /// Triangle list indexing into `vertices`
+    pub indices: Vec<u32>,
+}
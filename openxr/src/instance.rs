@@ -1,5 +1,5 @@
 use std::{
-    ffi::CString,
+    ffi::{c_void, CString},
     marker::PhantomData,
     mem::{self, MaybeUninit},
     ptr,
@@ -21,6 +21,9 @@ pub struct Instance {
 impl Instance {
     /// Take ownership of an existing instance handle
     ///
+    /// `enabled_extensions` is taken to be the extensions loaded in `exts`; use
+    /// `from_raw_with_extensions` to also record extensions unknown to these bindings.
+    ///
     /// # Safety
     ///
     /// `handle` must be the instance handle that was used to load `exts`.
@@ -29,10 +32,28 @@ impl Instance {
         handle: sys::Instance,
         exts: InstanceExtensions,
     ) -> Result<Self> {
+        let enabled_extensions = exts.enabled();
+        Self::from_raw_with_extensions(entry, handle, exts, enabled_extensions)
+    }
+
+    /// Take ownership of an existing instance handle, recording the extensions it was created with
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be the instance handle that was used to load `exts`, and must have been
+    /// created with `enabled_extensions` enabled.
+    pub unsafe fn from_raw_with_extensions(
+        entry: Entry,
+        handle: sys::Instance,
+        exts: InstanceExtensions,
+        enabled_extensions: ExtensionSet,
+    ) -> Result<Self> {
+        let raw = raw::Instance::load(&entry, handle)?;
         Ok(Self {
             inner: Arc::new(InstanceInner {
-                raw: raw::Instance::load(&entry, handle)?,
+                raw,
                 exts,
+                enabled_extensions,
                 handle,
                 entry,
                 set_name_lock: Mutex::new(()),
@@ -57,18 +78,34 @@ impl Instance {
         &self.inner.raw
     }
 
-    /// Access the internal extension function pointers
+    /// Access the function pointers of enabled extensions
+    ///
+    /// Fields for extensions that were not enabled are `None`. Use this to call extension entry
+    /// points that are not otherwise wrapped, without loading them again.
     #[inline]
     pub fn exts(&self) -> &InstanceExtensions {
         &self.inner.exts
     }
 
+    /// The extensions enabled when self was created
+    #[inline]
+    pub fn enabled_extensions(&self) -> &ExtensionSet {
+        &self.inner.enabled_extensions
+    }
+
+    /// Whether the extension named `name` was enabled when self was created
+    #[inline]
+    pub fn supports(&self, name: &str) -> bool {
+        self.inner.enabled_extensions.contains(name)
+    }
+
     /// Set the debug name of this `Instance`, if `XR_EXT_debug_utils` is loaded
     #[inline]
     pub fn set_name(&mut self, name: &str) -> Result<()> {
-        self.set_name_raw(self.as_raw().into_raw(), name)
+        self.set_name_raw(ObjectType::INSTANCE, self.as_raw().into_raw(), name)
     }
 
+    /// Query the name and version of the runtime
     #[inline]
     pub fn properties(&self) -> Result<InstanceProperties> {
         unsafe {
@@ -76,7 +113,10 @@ impl Instance {
                 ty: sys::InstanceProperties::TYPE,
                 ..mem::zeroed()
             };
-            cvt((self.fp().get_instance_properties)(self.as_raw(), &mut p))?;
+            cvt(
+                "xrGetInstanceProperties",
+                (self.fp().get_instance_properties)(self.as_raw(), &mut p),
+            )?;
             Ok(InstanceProperties {
                 runtime_version: p.runtime_version,
                 runtime_name: fixed_str(&p.runtime_name).into(),
@@ -84,32 +124,39 @@ impl Instance {
         }
     }
 
+    /// Get the runtime's name for `result`, e.g. `XR_ERROR_LAYER_INVALID`
+    ///
+    /// The `Display` impl of `sys::Result` already describes every code known to these bindings;
+    /// this additionally covers codes defined by extensions newer than the bindings.
     #[inline]
     pub fn result_to_string(&self, result: sys::Result) -> Result<String> {
         unsafe {
             let mut s = [0; sys::MAX_RESULT_STRING_SIZE];
-            cvt((self.fp().result_to_string)(
-                self.as_raw(),
-                result,
-                s.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrResultToString",
+                (self.fp().result_to_string)(self.as_raw(), result, s.as_mut_ptr()),
+            )?;
             Ok(fixed_str(&s).into())
         }
     }
 
+    /// Get the runtime's name for `ty`, e.g. `XR_TYPE_FRAME_END_INFO`
     #[inline]
     pub fn structure_type_to_string(&self, ty: StructureType) -> Result<String> {
         unsafe {
             let mut s = [0; sys::MAX_STRUCTURE_NAME_SIZE];
-            cvt((self.fp().structure_type_to_string)(
-                self.as_raw(),
-                ty,
-                s.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrStructureTypeToString",
+                (self.fp().structure_type_to_string)(self.as_raw(), ty, s.as_mut_ptr()),
+            )?;
             Ok(fixed_str(&s).into())
         }
     }
 
+    /// Identify the system of the given form factor
+    ///
+    /// Returns `ERROR_FORM_FACTOR_UNAVAILABLE` if such a system is supported but not currently
+    /// available, e.g. because a headset is unplugged, in which case the call may be retried later.
     #[inline]
     pub fn system(&self, form_factor: FormFactor) -> Result<SystemId> {
         let info = sys::SystemGetInfo {
@@ -119,23 +166,61 @@ impl Instance {
         };
         let mut out = SystemId::NULL;
         unsafe {
-            cvt((self.fp().get_system)(self.as_raw(), &info, &mut out))?;
+            cvt(
+                "xrGetSystem",
+                (self.fp().get_system)(self.as_raw(), &info, &mut out),
+            )?;
         }
         Ok(out)
     }
 
+    /// Query the vendor, name, graphics limits, and tracking capabilities of a system
+    ///
+    /// Capabilities specific to an extension are only queried if that extension is enabled.
     #[inline]
     pub fn system_properties(&self, system: SystemId) -> Result<SystemProperties> {
         unsafe {
+            let mut eye_gaze = sys::SystemEyeGazeInteractionPropertiesEXT {
+                ty: sys::SystemEyeGazeInteractionPropertiesEXT::TYPE,
+                ..mem::zeroed()
+            };
+            let mut color_space = sys::SystemColorSpacePropertiesFB {
+                ty: sys::SystemColorSpacePropertiesFB::TYPE,
+                ..mem::zeroed()
+            };
+            let mut space_warp = sys::SystemSpaceWarpPropertiesFB {
+                ty: sys::SystemSpaceWarpPropertiesFB::TYPE,
+                ..mem::zeroed()
+            };
+            let mut foveation_eye_tracked = sys::SystemFoveationEyeTrackedPropertiesMETA {
+                ty: sys::SystemFoveationEyeTrackedPropertiesMETA::TYPE,
+                ..mem::zeroed()
+            };
             let mut p = sys::SystemProperties {
                 ty: sys::SystemProperties::TYPE,
                 ..mem::zeroed()
             };
-            cvt((self.fp().get_system_properties)(
-                self.as_raw(),
-                system,
-                &mut p,
-            ))?;
+            let exts = self.exts();
+            if exts.ext_eye_gaze_interaction.is_some() {
+                eye_gaze.next = p.next;
+                p.next = &mut eye_gaze as *mut _ as _;
+            }
+            if exts.fb_color_space.is_some() {
+                color_space.next = p.next;
+                p.next = &mut color_space as *mut _ as _;
+            }
+            if exts.fb_space_warp.is_some() {
+                space_warp.next = p.next;
+                p.next = &mut space_warp as *mut _ as _;
+            }
+            if exts.meta_foveation_eye_tracked.is_some() {
+                foveation_eye_tracked.next = p.next;
+                p.next = &mut foveation_eye_tracked as *mut _ as _;
+            }
+            cvt(
+                "xrGetSystemProperties",
+                (self.fp().get_system_properties)(self.as_raw(), system, &mut p),
+            )?;
             Ok(SystemProperties {
                 system_id: p.system_id,
                 vendor_id: p.vendor_id,
@@ -145,48 +230,178 @@ impl Instance {
                     orientation_tracking: p.tracking_properties.orientation_tracking.into(),
                     position_tracking: p.tracking_properties.position_tracking.into(),
                 },
+                supports_eye_gaze_interaction: eye_gaze.supports_eye_gaze_interaction.into(),
+                color_space: exts.fb_color_space.map(|_| color_space.color_space),
+                space_warp: exts.fb_space_warp.map(|_| SystemSpaceWarpProperties {
+                    recommended_motion_vector_image_rect_width: space_warp
+                        .recommended_motion_vector_image_rect_width,
+                    recommended_motion_vector_image_rect_height: space_warp
+                        .recommended_motion_vector_image_rect_height,
+                }),
+                supports_foveation_eye_tracked: foveation_eye_tracked
+                    .supports_foveation_eye_tracked
+                    .into(),
             })
         }
     }
 
+    /// Deliver messages of the given `severities` and `types` from the runtime and API layers to
+    /// `callback` until the returned messenger is dropped
+    ///
+    /// `callback` may be invoked from any thread, but never concurrently, and must not itself call
+    /// into OpenXR. Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_EXT_debug_utils` was not
+    /// enabled.
+    #[inline]
+    pub fn create_debug_utils_messenger(
+        &self,
+        severities: DebugUtilsMessageSeverityFlagsEXT,
+        types: DebugUtilsMessageTypeFlagsEXT,
+        callback: DebugUtilsCallback,
+    ) -> Result<DebugUtilsMessenger> {
+        unsafe { DebugUtilsMessenger::new(self, severities, types, callback) }
+    }
+
     /// Construct a `Path` from a string
     ///
-    /// A `Path` should only be used with the instance that produced it.
+    /// A `Path` should only be used with the instance that produced it. Returns
+    /// `ERROR_PATH_FORMAT_INVALID` if `string` contains a null byte.
     #[inline]
     pub fn string_to_path(&self, string: &str) -> Result<Path> {
         let string = CString::new(string).map_err(|_| sys::Result::ERROR_PATH_FORMAT_INVALID)?;
         let mut out = Path::NULL;
         unsafe {
-            cvt((self.fp().string_to_path)(
-                self.as_raw(),
-                string.as_ptr(),
-                &mut out,
-            ))?;
+            cvt(
+                "xrStringToPath",
+                (self.fp().string_to_path)(self.as_raw(), string.as_ptr(), &mut out),
+            )?;
         }
         Ok(out)
     }
 
+    /// Retrieve the string a `Path` was constructed from
     #[inline]
     pub fn path_to_string(&self, path: Path) -> Result<String> {
-        get_str(|input, output, buf| unsafe {
+        get_str_trusted("xrPathToString", |input, output, buf| unsafe {
             (self.fp().path_to_string)(self.as_raw(), path, input, output, buf)
         })
     }
 
+    /// Convert a `Time` to the equivalent `CLOCK_MONOTONIC` time
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_KHR_convert_timespec_time` was not enabled.
+    #[inline]
+    pub fn time_to_timespec(&self, time: Time) -> Result<libc::timespec> {
+        let fp = self.convert_timespec_time()?;
+        unsafe {
+            let mut out = mem::zeroed();
+            cvt(
+                "xrConvertTimeToTimespecTimeKHR",
+                (fp.convert_time_to_timespec_time)(self.as_raw(), time, &mut out),
+            )?;
+            Ok(out)
+        }
+    }
+
+    /// Convert a `CLOCK_MONOTONIC` time to the equivalent `Time`
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_KHR_convert_timespec_time` was not enabled.
+    #[inline]
+    pub fn timespec_to_time(&self, ts: &libc::timespec) -> Result<Time> {
+        let fp = self.convert_timespec_time()?;
+        let mut out = Time::from_nanos(0);
+        unsafe {
+            cvt(
+                "xrConvertTimespecTimeToTimeKHR",
+                (fp.convert_timespec_time_to_time)(self.as_raw(), ts, &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Convert a `Time` to the equivalent `QueryPerformanceCounter` value
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_KHR_win32_convert_performance_counter_time`
+    /// was not enabled.
+    #[inline]
+    #[cfg(windows)]
+    pub fn time_to_win32_perf_counter(&self, time: Time) -> Result<i64> {
+        let fp = self.win32_convert_performance_counter_time()?;
+        unsafe {
+            let mut out: LARGE_INTEGER = mem::zeroed();
+            cvt(
+                "xrConvertTimeToWin32PerformanceCounterKHR",
+                (fp.convert_time_to_win32_performance_counter)(self.as_raw(), time, &mut out),
+            )?;
+            Ok(*out.QuadPart())
+        }
+    }
+
+    /// Convert a `QueryPerformanceCounter` value to the equivalent `Time`
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_KHR_win32_convert_performance_counter_time`
+    /// was not enabled.
+    #[inline]
+    #[cfg(windows)]
+    pub fn win32_perf_counter_to_time(&self, counter: i64) -> Result<Time> {
+        let fp = self.win32_convert_performance_counter_time()?;
+        let mut out = Time::from_nanos(0);
+        unsafe {
+            let mut raw: LARGE_INTEGER = mem::zeroed();
+            *raw.QuadPart_mut() = counter;
+            cvt(
+                "xrConvertWin32PerformanceCounterToTimeKHR",
+                (fp.convert_win32_performance_counter_to_time)(self.as_raw(), &raw, &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Get the GUID of the headset's audio output device, for selecting its WASAPI endpoint
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_OCULUS_audio_device_guid` was not enabled.
+    #[inline]
+    #[cfg(windows)]
+    pub fn audio_output_device_guid(&self) -> Result<String> {
+        let fp = self.audio_device_guid()?;
+        let mut buf = [0; sys::MAX_AUDIO_DEVICE_STR_SIZE_OCULUS];
+        unsafe {
+            cvt(
+                "xrGetAudioOutputDeviceGuidOculus",
+                (fp.get_audio_output_device_guid)(self.as_raw(), buf.as_mut_ptr()),
+            )?;
+        }
+        Ok(wide_str(&buf))
+    }
+
+    /// Get the GUID of the headset's audio input device, for selecting its WASAPI endpoint
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_OCULUS_audio_device_guid` was not enabled.
+    #[inline]
+    #[cfg(windows)]
+    pub fn audio_input_device_guid(&self) -> Result<String> {
+        let fp = self.audio_device_guid()?;
+        let mut buf = [0; sys::MAX_AUDIO_DEVICE_STR_SIZE_OCULUS];
+        unsafe {
+            cvt(
+                "xrGetAudioInputDeviceGuidOculus",
+                (fp.get_audio_input_device_guid)(self.as_raw(), buf.as_mut_ptr()),
+            )?;
+        }
+        Ok(wide_str(&buf))
+    }
+
     /// Identify the Vulkan instance extensions required by a system
     ///
     /// Returns a space-delimited list of Vulkan instance extension names.
     #[inline]
     pub fn vulkan_instance_extensions(&self, system: SystemId) -> Result<String> {
-        get_str(|input, output, buf| unsafe {
-            (self.vulkan().get_vulkan_instance_extensions)(
-                self.as_raw(),
-                system,
-                input,
-                output,
-                buf,
-            )
-        })
+        let fp = self.vulkan()?;
+        get_str_trusted(
+            "xrGetVulkanInstanceExtensionsKHR",
+            |input, output, buf| unsafe {
+                (fp.get_vulkan_instance_extensions)(self.as_raw(), system, input, output, buf)
+            },
+        )
     }
 
     /// Identify the Vulkan device extensions required by a system
@@ -194,31 +409,155 @@ impl Instance {
     /// Returns a space-delimited list of Vulkan device extension names.
     #[inline]
     pub fn vulkan_device_extensions(&self, system: SystemId) -> Result<String> {
-        get_str(|input, output, buf| unsafe {
-            (self.vulkan().get_vulkan_device_extensions)(self.as_raw(), system, input, output, buf)
-        })
+        let fp = self.vulkan()?;
+        get_str_trusted(
+            "xrGetVulkanDeviceExtensionsKHR",
+            |input, output, buf| unsafe {
+                (fp.get_vulkan_device_extensions)(self.as_raw(), system, input, output, buf)
+            },
+        )
     }
 
     /// Identify the Vulkan graphics device to use for a system
+    ///
+    /// # Safety
+    ///
+    /// `vk_instance` must be a valid Vulkan instance handle.
     #[inline]
-    pub fn vulkan_graphics_device(
+    pub unsafe fn vulkan_graphics_device(
         &self,
         system: SystemId,
         vk_instance: VkInstance,
     ) -> Result<VkPhysicalDevice> {
         let mut out = ptr::null();
-        unsafe {
-            cvt((self.vulkan().get_vulkan_graphics_device)(
+        cvt(
+            "xrGetVulkanGraphicsDeviceKHR",
+            (self.vulkan()?.get_vulkan_graphics_device)(
                 self.as_raw(),
                 system,
                 vk_instance,
                 &mut out,
-            ))?;
-        }
+            ),
+        )?;
+        Ok(out)
+    }
+
+    /// Create a Vulkan instance suitable for use with `system`
+    ///
+    /// Requires `XR_KHR_vulkan_enable2`. The runtime enables whatever Vulkan instance extensions
+    /// it needs in addition to those in `create_info`. The inner result holds the `VkResult` of
+    /// `vkCreateInstance` if Vulkan itself failed.
+    ///
+    /// # Safety
+    ///
+    /// `get_instance_proc_addr` must be a valid `vkGetInstanceProcAddr`, and `create_info` must
+    /// point to a valid `VkInstanceCreateInfo`.
+    pub unsafe fn create_vulkan_instance(
+        &self,
+        system: SystemId,
+        get_instance_proc_addr: PFN_vkGetInstanceProcAddr,
+        create_info: *const VkInstanceCreateInfo,
+    ) -> Result<std::result::Result<VkInstance, VkResult>> {
+        let info = sys::VulkanInstanceCreateInfoKHR {
+            ty: sys::VulkanInstanceCreateInfoKHR::TYPE,
+            next: ptr::null(),
+            system_id: system,
+            create_flags: Default::default(),
+            pfn_get_instance_proc_addr: Some(get_instance_proc_addr),
+            vulkan_create_info: create_info,
+            vulkan_allocator: ptr::null(),
+        };
+        let mut out = ptr::null();
+        let mut vk_result = 0;
+        cvt(
+            "xrCreateVulkanInstanceKHR",
+            (self.vulkan2()?.create_vulkan_instance)(
+                self.as_raw(),
+                &info,
+                &mut out,
+                &mut vk_result,
+            ),
+        )?;
+        Ok(if vk_result < 0 {
+            Err(vk_result)
+        } else {
+            Ok(out)
+        })
+    }
+
+    /// Create a Vulkan device suitable for use with `system`
+    ///
+    /// Requires `XR_KHR_vulkan_enable2`. `physical_device` must be the device identified by
+    /// `vulkan_graphics_device2`. The runtime enables whatever Vulkan device extensions it needs in
+    /// addition to those in `create_info`. The inner result holds the `VkResult` of
+    /// `vkCreateDevice` if Vulkan itself failed.
+    ///
+    /// # Safety
+    ///
+    /// `get_instance_proc_addr` must be a valid `vkGetInstanceProcAddr`, `physical_device` a valid
+    /// `VkPhysicalDevice`, and `create_info` must point to a valid `VkDeviceCreateInfo`.
+    pub unsafe fn create_vulkan_device(
+        &self,
+        system: SystemId,
+        get_instance_proc_addr: PFN_vkGetInstanceProcAddr,
+        physical_device: VkPhysicalDevice,
+        create_info: *const VkDeviceCreateInfo,
+    ) -> Result<std::result::Result<VkDevice, VkResult>> {
+        let info = sys::VulkanDeviceCreateInfoKHR {
+            ty: sys::VulkanDeviceCreateInfoKHR::TYPE,
+            next: ptr::null(),
+            system_id: system,
+            create_flags: Default::default(),
+            pfn_get_instance_proc_addr: Some(get_instance_proc_addr),
+            vulkan_physical_device: physical_device,
+            vulkan_create_info: create_info,
+            vulkan_allocator: ptr::null(),
+        };
+        let mut out = ptr::null();
+        let mut vk_result = 0;
+        cvt(
+            "xrCreateVulkanDeviceKHR",
+            (self.vulkan2()?.create_vulkan_device)(self.as_raw(), &info, &mut out, &mut vk_result),
+        )?;
+        Ok(if vk_result < 0 {
+            Err(vk_result)
+        } else {
+            Ok(out)
+        })
+    }
+
+    /// Identify the Vulkan graphics device to use for a system
+    ///
+    /// Requires `XR_KHR_vulkan_enable2`. `vk_instance` should have been created by
+    /// `create_vulkan_instance`.
+    ///
+    /// # Safety
+    ///
+    /// `vk_instance` must be a valid Vulkan instance handle.
+    #[inline]
+    pub unsafe fn vulkan_graphics_device2(
+        &self,
+        system: SystemId,
+        vk_instance: VkInstance,
+    ) -> Result<VkPhysicalDevice> {
+        let info = sys::VulkanGraphicsDeviceGetInfoKHR {
+            ty: sys::VulkanGraphicsDeviceGetInfoKHR::TYPE,
+            next: ptr::null(),
+            system_id: system,
+            vulkan_instance: vk_instance,
+        };
+        let mut out = ptr::null();
+        cvt(
+            "xrGetVulkanGraphicsDevice2KHR",
+            (self.vulkan2()?.get_vulkan_graphics_device2)(self.as_raw(), &info, &mut out),
+        )?;
         Ok(out)
     }
 
     /// Query graphics API version requirements
+    ///
+    /// The graphics device used to create a session must satisfy these requirements, and the
+    /// OpenXR specification requires that they be queried before `create_session` is called.
     pub fn graphics_requirements<G: Graphics>(&self, system: SystemId) -> Result<G::Requirements> {
         G::requirements(self, system)
     }
@@ -247,31 +586,73 @@ impl Instance {
         system: SystemId,
         info: &G::SessionCreateInfo,
     ) -> Result<(Session<G>, FrameWaiter, FrameStream<G>)> {
-        let handle = G::create_session(self, system, info)?;
+        self.create_session_with_chain(system, info, SessionCreateChain::new())
+    }
+
+    /// Like `create_session`, but with additional extension structures chained onto the
+    /// `XrSessionCreateInfo`
+    ///
+    /// # Safety
+    ///
+    /// As for `create_session`. Additionally, the extensions of every structure in `chain` must
+    /// be enabled, and any handles or pointers they contain must be valid.
+    #[inline]
+    pub unsafe fn create_session_with_chain<G: Graphics>(
+        &self,
+        system: SystemId,
+        info: &G::SessionCreateInfo,
+        chain: SessionCreateChain<'_>,
+    ) -> Result<(Session<G>, FrameWaiter, FrameStream<G>)> {
+        let handle = G::create_session(self, system, info, chain.head)?;
         Ok(Session::from_raw(self.clone(), handle))
     }
 
+    /// Like `create_session`, but creates an overlay session whose layers are composited on top
+    /// of those of the main session of some other application
+    ///
+    /// Overlays with higher `session_layers_placement` are composited above those with lower
+    /// values. `Event::MainSessionVisibilityChangedEXTX` reports whether the main session is
+    /// visible. Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_EXTX_overlay` was not enabled.
+    ///
+    /// # Safety
+    ///
+    /// As for `create_session`.
+    #[inline]
+    pub unsafe fn create_overlay_session<G: Graphics>(
+        &self,
+        system: SystemId,
+        info: &G::SessionCreateInfo,
+        create_flags: OverlaySessionCreateFlagsEXTX,
+        session_layers_placement: u32,
+    ) -> Result<(Session<G>, FrameWaiter, FrameStream<G>)> {
+        if self.exts().extx_overlay.is_none() {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        let mut overlay = sys::SessionCreateInfoOverlayEXTX {
+            ty: sys::SessionCreateInfoOverlayEXTX::TYPE,
+            next: ptr::null(),
+            create_flags,
+            session_layers_placement,
+        };
+        self.create_session_with_chain(system, info, SessionCreateChain::new().push(&mut overlay))
+    }
+
     /// Get the next event, if available
     ///
-    /// Returns immediately regardless of whether an event was available.
+    /// Returns immediately regardless of whether an event was available. Events of types unknown
+    /// to these bindings are returned as `Event::Other`.
     #[inline]
     pub fn poll_event<'a>(&self, storage: &'a mut EventDataBuffer) -> Result<Option<Event<'a>>> {
         unsafe {
-            // Work around a shortcoming in NLL as of 2019-03-22
-            let storage: *mut EventDataBuffer = storage;
-            loop {
-                let status = cvt((self.fp().poll_event)(
-                    self.as_raw(),
-                    (*storage).inner.as_mut_ptr(),
-                ))?;
-                if status == sys::Result::EVENT_UNAVAILABLE {
-                    return Ok(None);
-                }
-                debug_assert_eq!(status, sys::Result::SUCCESS);
-                if let x @ Some(_) = Event::from_raw((*storage).inner.as_ptr()) {
-                    return Ok(x);
-                }
+            let status = cvt(
+                "xrPollEvent",
+                (self.fp().poll_event)(self.as_raw(), storage.inner.as_mut_ptr()),
+            )?;
+            if status == sys::Result::EVENT_UNAVAILABLE {
+                return Ok(None);
             }
+            debug_assert_eq!(status, sys::Result::SUCCESS);
+            Ok(Some(Event::from_raw(storage.inner.as_ptr())))
         }
     }
 
@@ -281,7 +662,7 @@ impl Instance {
         &self,
         system: SystemId,
     ) -> Result<Vec<ViewConfigurationType>> {
-        get_arr(|cap, count, buf| unsafe {
+        get_arr_trusted("xrEnumerateViewConfigurations", |cap, count, buf| unsafe {
             (self.fp().enumerate_view_configurations)(self.as_raw(), system, cap, count, buf)
         })
     }
@@ -295,12 +676,15 @@ impl Instance {
     ) -> Result<ViewConfigurationProperties> {
         let out = unsafe {
             let mut x = sys::ViewConfigurationProperties::out(ptr::null_mut());
-            cvt((self.fp().get_view_configuration_properties)(
-                self.as_raw(),
-                system,
-                ty,
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetViewConfigurationProperties",
+                (self.fp().get_view_configuration_properties)(
+                    self.as_raw(),
+                    system,
+                    ty,
+                    x.as_mut_ptr(),
+                ),
+            )?;
             x.assume_init()
         };
         Ok(ViewConfigurationProperties {
@@ -309,63 +693,127 @@ impl Instance {
         })
     }
 
-    #[inline]
+    /// Query the per-view properties of a view configuration, in view order
+    ///
+    /// The recommended dimensions and sample counts are appropriate for allocating swapchains.
+    /// Depth ranges are reported if `XR_EXT_view_configuration_depth_range` was enabled on the
+    /// instance.
     pub fn enumerate_view_configuration_views(
         &self,
         system: SystemId,
         ty: ViewConfigurationType,
     ) -> Result<Vec<ViewConfigurationView>> {
-        let views = get_arr_init(
-            sys::ViewConfigurationView::out(ptr::null_mut()),
-            |capacity, count, buf| unsafe {
+        let with_depth_range = self.exts().ext_view_configuration_depth_range.is_some();
+        let mut count = 0;
+        unsafe {
+            cvt(
+                "xrEnumerateViewConfigurationViews",
+                (self.fp().enumerate_view_configuration_views)(
+                    self.as_raw(),
+                    system,
+                    ty,
+                    0,
+                    &mut count,
+                    ptr::null_mut(),
+                ),
+            )?;
+        }
+        // Like `get_arr_init`, but each view carries its own depth range
+        loop {
+            let mut depth_ranges = vec![
+                sys::ViewConfigurationDepthRangeEXT {
+                    ty: sys::ViewConfigurationDepthRangeEXT::TYPE,
+                    next: ptr::null_mut(),
+                    recommended_near_z: 0.0,
+                    min_near_z: 0.0,
+                    recommended_far_z: 0.0,
+                    max_far_z: 0.0,
+                };
+                if with_depth_range { count as usize } else { 0 }
+            ];
+            let mut views = (0..count as usize)
+                .map(|i| {
+                    let next = depth_ranges
+                        .get_mut(i)
+                        .map_or(ptr::null_mut(), |x| x as *mut _ as _);
+                    sys::ViewConfigurationView::out(next)
+                })
+                .collect::<Vec<_>>();
+            match cvt("xrEnumerateViewConfigurationViews", unsafe {
                 (self.fp().enumerate_view_configuration_views)(
                     self.as_raw(),
                     system,
                     ty,
-                    capacity,
                     count,
-                    buf as *mut _,
+                    &mut count,
+                    views.as_mut_ptr() as *mut _,
                 )
-            },
-        )?;
-        Ok(views
-            .into_iter()
-            .map(|x| {
-                let x = unsafe { x.assume_init() };
-                ViewConfigurationView {
-                    recommended_image_rect_width: x.recommended_image_rect_width,
-                    max_image_rect_width: x.max_image_rect_width,
-                    recommended_image_rect_height: x.recommended_image_rect_height,
-                    max_image_rect_height: x.max_image_rect_height,
-                    recommended_swapchain_sample_count: x.recommended_swapchain_sample_count,
-                    max_swapchain_sample_count: x.max_swapchain_sample_count,
+            }) {
+                Ok(_) => {
+                    views.truncate(count as usize);
+                    return Ok(views
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, x)| {
+                            let x = unsafe { x.assume_init() };
+                            ViewConfigurationView {
+                                recommended_image_rect_width: x.recommended_image_rect_width,
+                                max_image_rect_width: x.max_image_rect_width,
+                                recommended_image_rect_height: x.recommended_image_rect_height,
+                                max_image_rect_height: x.max_image_rect_height,
+                                recommended_swapchain_sample_count: x
+                                    .recommended_swapchain_sample_count,
+                                max_swapchain_sample_count: x.max_swapchain_sample_count,
+                                depth_range: depth_ranges.get(i).map(|x| {
+                                    ViewConfigurationDepthRange {
+                                        recommended_near_z: x.recommended_near_z,
+                                        min_near_z: x.min_near_z,
+                                        recommended_far_z: x.recommended_far_z,
+                                        max_far_z: x.max_far_z,
+                                    }
+                                }),
+                            }
+                        })
+                        .collect());
                 }
-            })
-            .collect())
+                Err(e) if e == sys::Result::ERROR_SIZE_INSUFFICIENT => {}
+                Err(e) => {
+                    return Err(e);
+                }
+            }
+        }
     }
 
+    /// Enumerate the environment blend modes supported by a view configuration
+    ///
+    /// Modes are returned in the runtime's order of preference, so applications should use the
+    /// first one they support.
     #[inline]
     pub fn enumerate_environment_blend_modes(
         &self,
         system: SystemId,
         view_configuration_type: ViewConfigurationType,
     ) -> Result<Vec<EnvironmentBlendMode>> {
-        get_arr(|cap, count, buf| unsafe {
-            (self.fp().enumerate_environment_blend_modes)(
-                self.as_raw(),
-                system,
-                view_configuration_type,
-                cap,
-                count,
-                buf,
-            )
-        })
+        get_arr_trusted(
+            "xrEnumerateEnvironmentBlendModes",
+            |cap, count, buf| unsafe {
+                (self.fp().enumerate_environment_blend_modes)(
+                    self.as_raw(),
+                    system,
+                    view_configuration_type,
+                    cap,
+                    count,
+                    buf,
+                )
+            },
+        )
     }
 
     /// Obtain the current `Time`
     ///
-    /// Requires KHR_convert_timespec_time. Most applications should use times from
-    /// `FrameStream::wait` and `Action::state` instead.
+    /// Most applications should use times from `FrameStream::wait` and `Action::state` instead.
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_KHR_convert_timespec_time` was not enabled on
+    /// the instance.
     #[inline]
     #[cfg(not(windows))]
     pub fn now(&self) -> Result<Time> {
@@ -374,24 +822,23 @@ impl Instance {
             libc::clock_gettime(libc::CLOCK_MONOTONIC, now.as_mut_ptr());
             let now = now.assume_init();
             let mut out = MaybeUninit::uninit();
-            cvt((self
-                .exts()
-                .khr_convert_timespec_time
-                .as_ref()
-                .expect("KHR_convert_timespec_time not loaded")
-                .convert_timespec_time_to_time)(
-                self.as_raw(),
-                &now,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrConvertTimespecTimeToTimeKHR",
+                (self.convert_timespec_time()?.convert_timespec_time_to_time)(
+                    self.as_raw(),
+                    &now,
+                    out.as_mut_ptr(),
+                ),
+            )?;
             Ok(out.assume_init())
         }
     }
 
     /// Obtain the current `Time`
     ///
-    /// Requires KHR_win32_convert_performance_counter_time. Most applications should use
-    /// times from `FrameStream::wait` and `Action::state` instead.
+    /// Most applications should use times from `FrameStream::wait` and `Action::state` instead.
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_KHR_win32_convert_performance_counter_time` was
+    /// not enabled on the instance.
     #[inline]
     #[cfg(windows)]
     pub fn now(&self) -> Result<Time> {
@@ -400,21 +847,25 @@ impl Instance {
             winapi::um::profileapi::QueryPerformanceCounter(now.as_mut_ptr());
             let now = now.assume_init();
             let mut out = MaybeUninit::uninit();
-            cvt((self
-                .exts()
-                .khr_win32_convert_performance_counter_time
-                .as_ref()
-                .expect("KHR_win32_convert_performance_counter_time not loaded")
-                .convert_win32_performance_counter_to_time)(
-                self.as_raw(),
-                &now,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrConvertWin32PerformanceCounterToTimeKHR",
+                (self
+                    .win32_convert_performance_counter_time()?
+                    .convert_win32_performance_counter_to_time)(
+                    self.as_raw(),
+                    &now,
+                    out.as_mut_ptr(),
+                ),
+            )?;
             Ok(out.assume_init())
         }
     }
 
     /// Specify default bindings for a well-known input archetype
+    ///
+    /// Must be called before the action sets containing the bound actions are attached with
+    /// `Session::attach_action_sets`. Returns `ERROR_PATH_UNSUPPORTED` if a binding path is not
+    /// valid for `interaction_profile`.
     #[inline]
     pub fn suggest_interaction_profile_bindings(
         &self,
@@ -429,16 +880,19 @@ impl Instance {
             suggested_bindings: bindings.as_ptr() as *const _ as _,
         };
         unsafe {
-            cvt((self.fp().suggest_interaction_profile_bindings)(
-                self.as_raw(),
-                &info,
-            ))?;
+            cvt(
+                "xrSuggestInteractionProfileBindings",
+                (self.fp().suggest_interaction_profile_bindings)(self.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
 
     /// Allocate a new [`ActionSet`]
     ///
+    /// Returns `ERROR_NAME_INVALID` or `ERROR_LOCALIZED_NAME_INVALID` if `name` or
+    /// `localized_name` respectively is empty or too long.
+    ///
     /// [`ActionSet`]: https://www.khronos.org/registry/OpenXR/specs/1.0/html/xrspec.html#input-action-creation
     #[inline]
     pub fn create_action_set(
@@ -447,17 +901,24 @@ impl Instance {
         localized_name: &str,
         priority: u32,
     ) -> Result<ActionSet> {
+        if name.is_empty() || name.len() >= sys::MAX_ACTION_SET_NAME_SIZE {
+            return Err(error(None, sys::Result::ERROR_NAME_INVALID));
+        }
+        if localized_name.is_empty()
+            || localized_name.len() >= sys::MAX_LOCALIZED_ACTION_SET_NAME_SIZE
+        {
+            return Err(error(None, sys::Result::ERROR_LOCALIZED_NAME_INVALID));
+        }
         let info = builder::ActionSetCreateInfo::new()
             .action_set_name(name)
             .localized_action_set_name(localized_name)
             .priority(priority);
         unsafe {
             let mut out = sys::ActionSet::NULL;
-            cvt((self.fp().create_action_set)(
-                self.as_raw(),
-                info.as_raw(),
-                &mut out,
-            ))?;
+            cvt(
+                "xrCreateActionSet",
+                (self.fp().create_action_set)(self.as_raw(), info.as_raw(), &mut out),
+            )?;
             Ok(ActionSet::from_raw(self.clone(), out))
         }
     }
@@ -466,13 +927,18 @@ impl Instance {
     // Internal helpers
     //
 
-    pub(crate) fn set_name_raw(&self, object: u64, name: &str) -> Result<()> {
+    pub(crate) fn set_name_raw(
+        &self,
+        object_type: ObjectType,
+        object: u64,
+        name: &str,
+    ) -> Result<()> {
         if let Some(fp) = self.exts().ext_debug_utils.as_ref() {
             let name = CString::new(name).unwrap();
             let info = sys::DebugUtilsObjectNameInfoEXT {
                 ty: sys::DebugUtilsObjectNameInfoEXT::TYPE,
                 next: ptr::null(),
-                object_type: ObjectType::INSTANCE,
+                object_type,
                 object_handle: object,
                 object_name: name.as_ptr(),
             };
@@ -480,36 +946,81 @@ impl Instance {
             // performance-relevant, so we use a conservative instance-global lock for simplicity.
             let guard = self.inner.set_name_lock.lock().unwrap();
             unsafe {
-                cvt((fp.set_debug_utils_object_name)(self.as_raw(), &info))?;
+                cvt(
+                    "xrSetDebugUtilsObjectNameEXT",
+                    (fp.set_debug_utils_object_name)(self.as_raw(), &info),
+                )?;
             }
             drop(guard);
         }
         Ok(())
     }
-    pub(crate) fn vulkan(&self) -> &raw::VulkanEnableKHR {
+    pub(crate) fn vulkan(&self) -> Result<&raw::VulkanEnableKHR> {
         self.exts()
             .khr_vulkan_enable
             .as_ref()
-            .expect("KHR_vulkan_enable not loaded")
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+    pub(crate) fn vulkan2(&self) -> Result<&raw::VulkanEnable2KHR> {
+        self.exts()
+            .khr_vulkan_enable2
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
     }
-    pub(crate) fn opengl(&self) -> &raw::OpenglEnableKHR {
+    pub(crate) fn opengl(&self) -> Result<&raw::OpenglEnableKHR> {
         self.exts()
             .khr_opengl_enable
             .as_ref()
-            .expect("KHR_opengl_enable not loaded")
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+    #[cfg(all(target_os = "android", feature = "opengles"))]
+    pub(crate) fn opengl_es(&self) -> Result<&raw::OpenglEsEnableKHR> {
+        self.exts()
+            .khr_opengl_es_enable
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
     }
     #[cfg(windows)]
-    pub(crate) fn d3d11(&self) -> &raw::D3d11EnableKHR {
+    pub(crate) fn d3d11(&self) -> Result<&raw::D3d11EnableKHR> {
         self.exts()
             .khr_d3d11_enable
             .as_ref()
-            .expect("KHR_d3d11_enable not loaded")
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
     }
-    pub(crate) fn visibility_mask(&self) -> &raw::VisibilityMaskKHR {
+    #[cfg(windows)]
+    pub(crate) fn d3d12(&self) -> Result<&raw::D3d12EnableKHR> {
+        self.exts()
+            .khr_d3d12_enable
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+    fn convert_timespec_time(&self) -> Result<&raw::ConvertTimespecTimeKHR> {
+        self.exts()
+            .khr_convert_timespec_time
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+    #[cfg(windows)]
+    fn win32_convert_performance_counter_time(
+        &self,
+    ) -> Result<&raw::Win32ConvertPerformanceCounterTimeKHR> {
+        self.exts()
+            .khr_win32_convert_performance_counter_time
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+    #[cfg(windows)]
+    fn audio_device_guid(&self) -> Result<&raw::AudioDeviceGuidOCULUS> {
+        self.exts()
+            .oculus_audio_device_guid
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+    pub(crate) fn visibility_mask(&self) -> Result<&raw::VisibilityMaskKHR> {
         self.exts()
             .khr_visibility_mask
             .as_ref()
-            .expect("KHR_visibility_mask not loaded")
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
     }
 }
 
@@ -518,6 +1029,7 @@ struct InstanceInner {
     handle: sys::Instance,
     raw: raw::Instance,
     exts: InstanceExtensions,
+    enabled_extensions: ExtensionSet,
     set_name_lock: Mutex<()>,
 }
 
@@ -529,8 +1041,83 @@ impl Drop for InstanceInner {
     }
 }
 
+/// Extension structures to chain onto the `XrSessionCreateInfo` used by
+/// `Instance::create_session_with_chain`, after the graphics binding
+///
+/// # Example
+///
+/// ```no_run
+/// # unsafe fn dummy(instance: &openxr::Instance, system: openxr::SystemId) {
+/// let mut overlay = openxr::sys::SessionCreateInfoOverlayEXTX {
+///     ty: openxr::sys::SessionCreateInfoOverlayEXTX::TYPE,
+///     next: std::ptr::null(),
+///     create_flags: openxr::OverlaySessionCreateFlagsEXTX::EMPTY,
+///     session_layers_placement: 1,
+/// };
+/// let chain = openxr::SessionCreateChain::new().push(&mut overlay);
+/// let (session, frame_waiter, frame_stream) = instance
+///     .create_session_with_chain::<openxr::Headless>(system, &(), chain)
+///     .unwrap();
+/// # }
+/// ```
+pub struct SessionCreateChain<'a> {
+    head: *const c_void,
+    _marker: PhantomData<&'a mut ()>,
+}
+
+impl<'a> SessionCreateChain<'a> {
+    /// An empty chain
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            head: ptr::null(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Chain `x` onto the front of the chain, overwriting its `next` pointer
+    #[inline]
+    pub fn push<T: ExtendsSessionCreateInfo>(mut self, x: &'a mut T) -> Self {
+        *x.next_mut() = self.head;
+        self.head = x as *const T as *const c_void;
+        self
+    }
+}
+
+impl Default for SessionCreateChain<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Structures which may be chained onto an `XrSessionCreateInfo` by `SessionCreateChain`
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(C)]` OpenXR structures whose `structextends` includes
+/// `XrSessionCreateInfo`, and `next_mut` must return their `next` field.
+pub unsafe trait ExtendsSessionCreateInfo {
+    fn next_mut(&mut self) -> &mut *const c_void;
+}
+
+unsafe impl ExtendsSessionCreateInfo for sys::SessionCreateInfoOverlayEXTX {
+    #[inline]
+    fn next_mut(&mut self) -> &mut *const c_void {
+        &mut self.next
+    }
+}
+
+unsafe impl ExtendsSessionCreateInfo for sys::HolographicWindowAttachmentMSFT {
+    #[inline]
+    fn next_mut(&mut self) -> &mut *const c_void {
+        &mut self.next
+    }
+}
+
+/// Identifies the runtime backing an `Instance`
 #[derive(Debug, Clone)]
 pub struct InstanceProperties {
+    /// Runtime-defined version, not necessarily related to the OpenXR version
     pub runtime_version: Version,
     pub runtime_name: String,
 }
@@ -542,6 +1129,30 @@ pub struct SystemProperties {
     pub system_name: String,
     pub graphics_properties: SystemGraphicsProperties,
     pub tracking_properties: SystemTrackingProperties,
+    /// Whether the system supports `XR_EXT_eye_gaze_interaction`
+    ///
+    /// Always false if the extension is not enabled.
+    pub supports_eye_gaze_interaction: bool,
+    /// The system's native color space, if `XR_FB_color_space` is enabled
+    pub color_space: Option<ColorSpaceFB>,
+    /// Motion vector image recommendations for `CompositionLayerSpaceWarpInfoFB`, if
+    /// `XR_FB_space_warp` is enabled
+    pub space_warp: Option<SystemSpaceWarpProperties>,
+    /// Whether the system supports eye-tracked foveation via `XR_META_foveation_eye_tracked`
+    ///
+    /// Always false if the extension is not enabled.
+    pub supports_foveation_eye_tracked: bool,
+}
+
+/// Recommended size of the motion vector images submitted for Application SpaceWarp
+///
+/// Motion vector swapchains should be created at this size, which is typically smaller than the
+/// color images, with a 16-bit float RGBA format (e.g. `GL_RGBA16F` or
+/// `VK_FORMAT_R16G16B16A16_SFLOAT`) and the usage `SwapchainUsageFlags::COLOR_ATTACHMENT`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SystemSpaceWarpProperties {
+    pub recommended_motion_vector_image_rect_width: u32,
+    pub recommended_motion_vector_image_rect_height: u32,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -556,7 +1167,8 @@ pub struct ViewConfigurationProperties {
     pub fov_mutable: bool,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Limits and recommendations for rendering an individual view
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct ViewConfigurationView {
     pub recommended_image_rect_width: u32,
     pub max_image_rect_width: u32,
@@ -564,6 +1176,21 @@ pub struct ViewConfigurationView {
     pub max_image_rect_height: u32,
     pub recommended_swapchain_sample_count: u32,
     pub max_swapchain_sample_count: u32,
+    /// Requires `XR_EXT_view_configuration_depth_range`
+    pub depth_range: Option<ViewConfigurationDepthRange>,
+}
+
+/// Near and far plane distances, in meters, for which a view is optimized
+///
+/// Projection matrices should use the recommended distances where possible, and must stay within
+/// `min_near_z` and `max_far_z`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ViewConfigurationDepthRange {
+    pub recommended_near_z: f32,
+    pub min_near_z: f32,
+    pub recommended_far_z: f32,
+    /// May be infinite
+    pub max_far_z: f32,
 }
 
 pub struct EventDataBuffer {
@@ -583,6 +1210,7 @@ impl EventDataBuffer {
     }
 }
 
+/// A suggested association between an action and an input source path
 #[repr(transparent)]
 #[derive(Copy, Clone)]
 pub struct Binding<'a> {
@@ -596,7 +1224,7 @@ impl<'a> Binding<'a> {
         Self {
             _inner: sys::ActionSuggestedBinding {
                 action: action.as_raw(),
-                binding: binding,
+                binding,
             },
             _marker: PhantomData,
         }
@@ -3,7 +3,8 @@
 use std::os::raw::c_char;
 
 pub use sys::{
-    self, Duration, Path, SystemId, Time, Version, CURRENT_API_VERSION, FREQUENCY_UNSPECIFIED,
+    self, AsyncRequestIdFB, Duration, Path, SystemId, Time, Version, CURRENT_API_VERSION,
+    FREQUENCY_UNSPECIFIED,
 };
 
 mod generated;
@@ -26,11 +27,45 @@ mod action_set;
 pub use action_set::*;
 mod action;
 pub use action::*;
+mod hand_tracker;
+pub use hand_tracker::*;
+mod body_tracker;
+pub use body_tracker::*;
+mod eye_tracker;
+pub use eye_tracker::*;
+mod face_tracker;
+pub use face_tracker::*;
+mod facial_tracker;
+pub use facial_tracker::*;
+mod foveation;
+pub use foveation::*;
+mod passthrough;
+pub use passthrough::*;
+mod triangle_mesh;
+pub use triangle_mesh::*;
+mod spatial_anchor;
+pub use spatial_anchor::*;
+mod scene_understanding;
+pub use scene_understanding::*;
+mod debug_utils;
+pub use debug_utils::*;
+mod session_state;
+pub use session_state::*;
+pub mod util;
+use util::two_call::{get_arr, get_arr_init, get_arr_trusted, get_str_trusted};
+mod error;
+pub use error::*;
 
+#[cfg(target_os = "android")]
+pub use builder::SwapchainStateAndroidSurfaceDimensionsFB;
 pub use builder::{
-    CompositionLayerBase, CompositionLayerCubeKHR, CompositionLayerCylinderKHR,
-    CompositionLayerEquirectKHR, CompositionLayerProjection, CompositionLayerProjectionView,
-    CompositionLayerQuad, HapticBase, HapticVibration, SwapchainSubImage,
+    CompositionLayerAlphaBlendFB, CompositionLayerBase, CompositionLayerColorScaleBiasKHR,
+    CompositionLayerCubeKHR, CompositionLayerCylinderKHR, CompositionLayerDepthInfoKHR,
+    CompositionLayerEquirect2KHR, CompositionLayerEquirectKHR, CompositionLayerPassthroughFB,
+    CompositionLayerProjection, CompositionLayerProjectionView, CompositionLayerQuad,
+    CompositionLayerSettingsFB, CompositionLayerSpaceWarpInfoFB, HapticBase, HapticVibration,
+    SwapchainStateBaseFB, SwapchainStateFoveationFB, SwapchainStateSamplerOpenGLESFB,
+    SwapchainStateSamplerVulkanFB, SwapchainSubImage,
 };
 
 pub type Result<T> = std::result::Result<T, sys::Result>;
@@ -41,16 +76,29 @@ pub const USER_HAND_RIGHT: &str = "/user/hand/right";
 pub const USER_HEAD: &str = "/user/head";
 pub const USER_GAMEPAD: &str = "/user/gamepad";
 pub const USER_TREADMILL: &str = "/user/treadmill";
+/// Requires `XR_EXT_eye_gaze_interaction`
+pub const USER_EYES_EXT: &str = "/user/eyes_ext";
+
+/// Interaction profile for `XR_EXT_eye_gaze_interaction`
+pub const EYE_GAZE_INTERACTION_PROFILE_EXT: &str = "/interaction_profiles/ext/eye_gaze_interaction";
+/// Pose of the user's combined eye gaze, in the `EYE_GAZE_INTERACTION_PROFILE_EXT` profile
+pub const USER_EYES_EXT_GAZE_POSE: &str = "/user/eyes_ext/input/gaze_ext/pose";
 
 // FFI helpers
-fn cvt(x: sys::Result) -> Result<sys::Result> {
+fn cvt(function: &'static str, x: sys::Result) -> Result<sys::Result> {
     if x.into_raw() >= 0 {
         Ok(x)
     } else {
-        Err(x)
+        Err(error(Some(function), x))
     }
 }
 
+/// Record where `result` came from for `Error::from`, then pass it through
+fn error(function: Option<&'static str>, result: sys::Result) -> sys::Result {
+    record_error(function, result);
+    result
+}
+
 fn place_cstr(out: &mut [c_char], s: &str) {
     if s.len() + 1 > out.len() {
         panic!(
@@ -65,8 +113,17 @@ fn place_cstr(out: &mut [c_char], s: &str) {
     out[s.len()] = 0;
 }
 
-unsafe fn fixed_str<'a>(x: &'a [c_char]) -> &'a str {
-    std::str::from_utf8_unchecked(std::ffi::CStr::from_ptr(x.as_ptr()).to_bytes())
+/// Stops at the first null, or the end of the buffer if there is none
+fn fixed_str(x: &[c_char]) -> std::borrow::Cow<'_, str> {
+    let bytes: &[u8] = unsafe { &*(x as *const [c_char] as *const _) };
+    let end = bytes.iter().position(|&x| x == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end])
+}
+
+#[cfg(windows)]
+fn wide_str(x: &[u16]) -> String {
+    let end = x.iter().position(|&x| x == 0).unwrap_or(x.len());
+    String::from_utf16_lossy(&x[..end])
 }
 
 /// Includes null for convenience of comparison with C string constants
@@ -74,61 +131,3 @@ fn fixed_str_bytes<'a>(x: &'a [c_char]) -> &'a [u8] {
     let end = x.iter().position(|&x| x == 0).unwrap();
     unsafe { std::mem::transmute(&x[..end + 1]) }
 }
-
-fn get_str(mut getter: impl FnMut(u32, &mut u32, *mut c_char) -> sys::Result) -> Result<String> {
-    let mut bytes = get_arr(|x, y, z| getter(x, y, z as _))?;
-    // Strip null byte
-    bytes.truncate(bytes.len() - 1);
-    unsafe { Ok(String::from_utf8_unchecked(bytes)) }
-}
-
-fn get_arr<T: Copy>(
-    mut getter: impl FnMut(u32, &mut u32, *mut T) -> sys::Result,
-) -> Result<Vec<T>> {
-    let mut output = 0;
-    cvt(getter(0, &mut output, std::ptr::null_mut()))?;
-    let mut buffer = Vec::with_capacity(output as usize);
-    loop {
-        match cvt(getter(
-            buffer.capacity() as u32,
-            &mut output,
-            buffer.as_mut_ptr() as _,
-        )) {
-            Ok(_) => {
-                unsafe {
-                    buffer.set_len(output as usize);
-                }
-                return Ok(buffer);
-            }
-            Err(sys::Result::ERROR_SIZE_INSUFFICIENT) => {
-                buffer.reserve(output as usize - buffer.capacity());
-            }
-            Err(e) => {
-                return Err(e);
-            }
-        }
-    }
-}
-
-fn get_arr_init<T: Copy>(
-    init: T,
-    mut getter: impl FnMut(u32, &mut u32, *mut T) -> sys::Result,
-) -> Result<Vec<T>> {
-    let mut output = 0;
-    cvt(getter(0, &mut output, std::ptr::null_mut()))?;
-    let mut buffer = vec![init; output as usize];
-    loop {
-        match cvt(getter(output, &mut output, buffer.as_mut_ptr() as _)) {
-            Ok(_) => {
-                buffer.truncate(output as usize);
-                return Ok(buffer);
-            }
-            Err(sys::Result::ERROR_SIZE_INSUFFICIENT) => {
-                buffer.resize(output as usize, init);
-            }
-            Err(e) => {
-                return Err(e);
-            }
-        }
-    }
-}
@@ -0,0 +1,197 @@
+use std::{ptr, sync::Arc};
+
+use crate::*;
+
+/// The passthrough feature of a session, from which passthrough layers are created
+///
+/// Created by `Session::create_passthrough`. Requires `XR_FB_passthrough`.
+pub struct Passthrough {
+    session: Arc<session::SessionInner>,
+    handle: sys::PassthroughFB,
+}
+
+impl Passthrough {
+    /// Take ownership of an existing passthrough handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid passthrough handle associated with `session`, which must have been
+    /// created with `XR_FB_passthrough` enabled.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(session: Session<G>, handle: sys::PassthroughFB) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+        }
+    }
+
+    /// Access the raw passthrough handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::PassthroughFB {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// Start the passthrough feature, if it was not created with
+    /// `PassthroughFlagsFB::IS_RUNNING_AT_CREATION`
+    #[inline]
+    pub fn start(&self) -> Result<()> {
+        unsafe {
+            cvt(
+                "xrPassthroughStartFB",
+                (fp(&self.session).passthrough_start)(self.as_raw()),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Pause the passthrough feature, stopping all of its layers
+    #[inline]
+    pub fn pause(&self) -> Result<()> {
+        unsafe {
+            cvt(
+                "xrPassthroughPauseFB",
+                (fp(&self.session).passthrough_pause)(self.as_raw()),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Create a layer which can be displayed with a `CompositionLayerPassthroughFB`
+    ///
+    /// With `XR_FB_passthrough_keyboard_hands`, `purpose` may be `TRACKED_KEYBOARD_HANDS` to show
+    /// the user's real hands over a tracked keyboard.
+    #[inline]
+    pub fn create_layer(
+        &self,
+        flags: PassthroughFlagsFB,
+        purpose: PassthroughLayerPurposeFB,
+    ) -> Result<PassthroughLayer> {
+        let info = sys::PassthroughLayerCreateInfoFB {
+            ty: sys::PassthroughLayerCreateInfoFB::TYPE,
+            next: ptr::null(),
+            passthrough: self.as_raw(),
+            flags,
+            purpose,
+        };
+        let mut out = sys::PassthroughLayerFB::NULL;
+        unsafe {
+            cvt(
+                "xrCreatePassthroughLayerFB",
+                (fp(&self.session).create_passthrough_layer)(self.session.handle, &info, &mut out),
+            )?;
+        }
+        Ok(PassthroughLayer {
+            session: self.session.clone(),
+            handle: out,
+        })
+    }
+}
+
+impl Drop for Passthrough {
+    fn drop(&mut self) {
+        unsafe {
+            (fp(&self.session).destroy_passthrough)(self.handle);
+        }
+    }
+}
+
+/// A layer of passthrough imagery
+///
+/// Created by `Passthrough::create_layer`, and displayed by passing its raw handle to
+/// `CompositionLayerPassthroughFB::layer_handle`.
+pub struct PassthroughLayer {
+    session: Arc<session::SessionInner>,
+    handle: sys::PassthroughLayerFB,
+}
+
+impl PassthroughLayer {
+    /// Access the raw passthrough layer handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::PassthroughLayerFB {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// Stop rendering this layer, allowing the runtime to release its resources
+    #[inline]
+    pub fn pause(&self) -> Result<()> {
+        unsafe {
+            cvt(
+                "xrPassthroughLayerPauseFB",
+                (fp(&self.session).passthrough_layer_pause)(self.as_raw()),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Resume rendering this layer after `pause`
+    #[inline]
+    pub fn resume(&self) -> Result<()> {
+        unsafe {
+            cvt(
+                "xrPassthroughLayerResumeFB",
+                (fp(&self.session).passthrough_layer_resume)(self.as_raw()),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Set the opacity, from 0 to 1, of the user's left and right hands as shown over a tracked
+    /// keyboard
+    ///
+    /// Only meaningful for layers created with `PassthroughLayerPurposeFB::TRACKED_KEYBOARD_HANDS`
+    /// or `TRACKED_KEYBOARD_MASKED_HANDS`. Returns `ERROR_EXTENSION_NOT_PRESENT` if
+    /// `XR_FB_passthrough_keyboard_hands` was not enabled on the instance.
+    #[inline]
+    pub fn set_keyboard_hands_intensity(&self, left: f32, right: f32) -> Result<()> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_passthrough_keyboard_hands
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let intensity = sys::PassthroughKeyboardHandsIntensityFB {
+            ty: sys::PassthroughKeyboardHandsIntensityFB::TYPE,
+            next: ptr::null(),
+            left_hand_intensity: left,
+            right_hand_intensity: right,
+        };
+        unsafe {
+            cvt(
+                "xrPassthroughLayerSetKeyboardHandsIntensityFB",
+                (fp.passthrough_layer_set_keyboard_hands_intensity)(self.as_raw(), &intensity),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for PassthroughLayer {
+    fn drop(&mut self) {
+        unsafe {
+            (fp(&self.session).destroy_passthrough_layer)(self.handle);
+        }
+    }
+}
+
+// Private helper
+#[inline]
+fn fp(session: &session::SessionInner) -> &raw::PassthroughFB {
+    session
+        .instance
+        .exts()
+        .fb_passthrough
+        .as_ref()
+        .expect("FB_passthrough not loaded")
+}
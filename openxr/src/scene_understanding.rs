@@ -0,0 +1,322 @@
+use std::{ptr, sync::Arc};
+
+use crate::*;
+
+/// Computes models of the physical environment, such as walls, floors, and meshes
+///
+/// Created by `Session::create_scene_observer`. Requires `XR_MSFT_scene_understanding`.
+pub struct SceneObserver {
+    session: Arc<session::SessionInner>,
+    handle: sys::SceneObserverMSFT,
+}
+
+impl SceneObserver {
+    /// Take ownership of an existing scene observer handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid scene observer handle associated with `session`, which must have
+    /// been created with `XR_MSFT_scene_understanding` enabled.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(
+        session: Session<G>,
+        handle: sys::SceneObserverMSFT,
+    ) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+        }
+    }
+
+    /// Access the raw scene observer handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::SceneObserverMSFT {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// Begin computing a new scene containing `features` within `bounds`
+    ///
+    /// Computation is asynchronous; poll `compute_state` until it reports completion, then call
+    /// `create_scene`.
+    pub fn compute_new_scene(
+        &self,
+        features: &[SceneComputeFeatureMSFT],
+        consistency: SceneComputeConsistencyMSFT,
+        bounds: &SceneBounds<'_>,
+    ) -> Result<()> {
+        assert_eq!(&*self.session as *const session::SessionInner, &*bounds.space.session as *const session::SessionInner,
+                   "`self` and `bounds.space` must have been created, allocated, or retrieved from the same `Session`");
+        let info = sys::NewSceneComputeInfoMSFT {
+            ty: sys::NewSceneComputeInfoMSFT::TYPE,
+            next: ptr::null(),
+            requested_feature_count: features.len() as u32,
+            requested_features: features.as_ptr(),
+            consistency,
+            bounds: sys::SceneBoundsMSFT {
+                space: bounds.space.as_raw(),
+                time: bounds.time,
+                sphere_count: bounds.spheres.len() as u32,
+                spheres: bounds.spheres.as_ptr(),
+                box_count: bounds.boxes.len() as u32,
+                boxes: bounds.boxes.as_ptr(),
+                frustum_count: bounds.frustums.len() as u32,
+                frustums: bounds.frustums.as_ptr(),
+            },
+        };
+        unsafe {
+            cvt(
+                "xrComputeNewSceneMSFT",
+                (fp(&self.session).compute_new_scene)(self.as_raw(), &info),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Progress of the most recent `compute_new_scene`
+    #[inline]
+    pub fn compute_state(&self) -> Result<SceneComputeStateMSFT> {
+        let mut out = SceneComputeStateMSFT::NONE;
+        unsafe {
+            cvt(
+                "xrGetSceneComputeStateMSFT",
+                (fp(&self.session).get_scene_compute_state)(self.as_raw(), &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Access the scene produced by the most recent `compute_new_scene`
+    ///
+    /// Fails with `ERROR_COMPUTE_NEW_SCENE_NOT_COMPLETED_MSFT` unless `compute_state` reports
+    /// `COMPLETED`.
+    #[inline]
+    pub fn create_scene(&self) -> Result<Scene<'_>> {
+        let info = sys::SceneCreateInfoMSFT {
+            ty: sys::SceneCreateInfoMSFT::TYPE,
+            next: ptr::null(),
+        };
+        let mut out = sys::SceneMSFT::NULL;
+        unsafe {
+            cvt(
+                "xrCreateSceneMSFT",
+                (fp(&self.session).create_scene)(self.as_raw(), &info, &mut out),
+            )?;
+        }
+        Ok(Scene {
+            observer: self,
+            handle: out,
+        })
+    }
+}
+
+impl Drop for SceneObserver {
+    fn drop(&mut self) {
+        unsafe {
+            (fp(&self.session).destroy_scene_observer)(self.handle);
+        }
+    }
+}
+
+/// Volumes of space to compute a scene within, as of `time`
+#[derive(Copy, Clone)]
+pub struct SceneBounds<'a> {
+    /// The space the bounds are defined in
+    pub space: &'a Space,
+    pub time: Time,
+    pub spheres: &'a [SceneSphereBoundMSFT],
+    pub boxes: &'a [SceneOrientedBoxBoundMSFT],
+    pub frustums: &'a [SceneFrustumBoundMSFT],
+}
+
+/// A snapshot of the physical environment computed by a `SceneObserver`
+pub struct Scene<'a> {
+    observer: &'a SceneObserver,
+    handle: sys::SceneMSFT,
+}
+
+impl Scene<'_> {
+    /// Access the raw scene handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::SceneMSFT {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        self.observer.instance()
+    }
+
+    /// Get all components of type `ty`
+    pub fn components(&self, ty: SceneComponentTypeMSFT) -> Result<Vec<sys::SceneComponentMSFT>> {
+        Ok(self.get_components(ty, false)?.0)
+    }
+
+    /// Get all mesh components of type `ty`, i.e. `VISUAL_MESH` or `COLLIDER_MESH`, together with
+    /// the identifiers of their buffers
+    pub fn meshes(
+        &self,
+        ty: SceneComponentTypeMSFT,
+    ) -> Result<Vec<(sys::SceneComponentMSFT, sys::SceneMeshMSFT)>> {
+        let (components, meshes) = self.get_components(ty, true)?;
+        Ok(components.into_iter().zip(meshes).collect())
+    }
+
+    /// Read the vertices and triangle indices of a mesh identified by `meshes`
+    pub fn mesh_buffers(&self, mesh_buffer_id: u64) -> Result<SceneMeshBuffers> {
+        let info = sys::SceneMeshBuffersGetInfoMSFT {
+            ty: sys::SceneMeshBuffersGetInfoMSFT::TYPE,
+            next: ptr::null(),
+            mesh_buffer_id,
+        };
+        let mut out = SceneMeshBuffers {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        };
+        unsafe {
+            let (vertex_count, index_count) = self.get_mesh_buffers(&info, &mut out)?;
+            out.vertices.reserve_exact(vertex_count);
+            out.indices.reserve_exact(index_count);
+            let (vertex_count, index_count) = self.get_mesh_buffers(&info, &mut out)?;
+            out.vertices.set_len(vertex_count);
+            out.indices.set_len(index_count);
+        }
+        Ok(out)
+    }
+
+    /// Fill the spare capacity of `out`, returning the number of vertices and indices available
+    unsafe fn get_mesh_buffers(
+        &self,
+        info: &sys::SceneMeshBuffersGetInfoMSFT,
+        out: &mut SceneMeshBuffers,
+    ) -> Result<(usize, usize)> {
+        let mut indices = sys::SceneMeshIndicesUint32MSFT {
+            ty: sys::SceneMeshIndicesUint32MSFT::TYPE,
+            next: ptr::null_mut(),
+            index_capacity_input: out.indices.capacity() as u32,
+            index_count_output: 0,
+            indices: out.indices.as_mut_ptr(),
+        };
+        let mut vertices = sys::SceneMeshVertexBufferMSFT {
+            ty: sys::SceneMeshVertexBufferMSFT::TYPE,
+            next: &mut indices as *mut _ as _,
+            vertex_capacity_input: out.vertices.capacity() as u32,
+            vertex_count_output: 0,
+            vertices: out.vertices.as_mut_ptr(),
+        };
+        let mut buffers = sys::SceneMeshBuffersMSFT {
+            ty: sys::SceneMeshBuffersMSFT::TYPE,
+            next: &mut vertices as *mut _ as _,
+        };
+        cvt(
+            "xrGetSceneMeshBuffersMSFT",
+            (self.fp().get_scene_mesh_buffers)(self.as_raw(), info, &mut buffers),
+        )?;
+        Ok((
+            vertices.vertex_count_output as usize,
+            indices.index_count_output as usize,
+        ))
+    }
+
+    fn get_components(
+        &self,
+        ty: SceneComponentTypeMSFT,
+        with_meshes: bool,
+    ) -> Result<(Vec<sys::SceneComponentMSFT>, Vec<sys::SceneMeshMSFT>)> {
+        let info = sys::SceneComponentsGetInfoMSFT {
+            ty: sys::SceneComponentsGetInfoMSFT::TYPE,
+            next: ptr::null(),
+            component_type: ty,
+        };
+        let mut out = (Vec::new(), Vec::new());
+        unsafe {
+            let count = self.get_components_into(&info, with_meshes, &mut out)?;
+            out.0.reserve_exact(count);
+            if with_meshes {
+                out.1.reserve_exact(count);
+            }
+            let count = self.get_components_into(&info, with_meshes, &mut out)?;
+            out.0.set_len(count);
+            if with_meshes {
+                out.1.set_len(count);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Fill the spare capacity of `out`, returning the number of components available
+    unsafe fn get_components_into(
+        &self,
+        info: &sys::SceneComponentsGetInfoMSFT,
+        with_meshes: bool,
+        out: &mut (Vec<sys::SceneComponentMSFT>, Vec<sys::SceneMeshMSFT>),
+    ) -> Result<usize> {
+        // The runtime requires one mesh per component
+        let capacity = if with_meshes {
+            out.0.capacity().min(out.1.capacity())
+        } else {
+            out.0.capacity()
+        };
+        let mut meshes = sys::SceneMeshesMSFT {
+            ty: sys::SceneMeshesMSFT::TYPE,
+            next: ptr::null_mut(),
+            scene_mesh_count: capacity as u32,
+            scene_meshes: out.1.as_mut_ptr(),
+        };
+        let mut components = sys::SceneComponentsMSFT {
+            ty: sys::SceneComponentsMSFT::TYPE,
+            next: if with_meshes {
+                &mut meshes as *mut _ as _
+            } else {
+                ptr::null_mut()
+            },
+            component_capacity_input: capacity as u32,
+            component_count_output: 0,
+            components: out.0.as_mut_ptr(),
+        };
+        cvt(
+            "xrGetSceneComponentsMSFT",
+            (self.fp().get_scene_components)(self.as_raw(), info, &mut components),
+        )?;
+        Ok(components.component_count_output as usize)
+    }
+
+    // Private helper
+    #[inline]
+    fn fp(&self) -> &raw::SceneUnderstandingMSFT {
+        fp(&self.observer.session)
+    }
+}
+
+impl Drop for Scene<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp().destroy_scene)(self.handle);
+        }
+    }
+}
+
+/// Geometry of a scene mesh, retrieved by `Scene::mesh_buffers`
+#[derive(Clone)]
+pub struct SceneMeshBuffers {
+    pub vertices: Vec<Vector3f>,
+    /// Triangle list indexing into `vertices`
+    pub indices: Vec<u32>,
+}
+
+// Private helper
+#[inline]
+fn fp(session: &session::SessionInner) -> &raw::SceneUnderstandingMSFT {
+    session
+        .instance
+        .exts()
+        .msft_scene_understanding
+        .as_ref()
+        .expect("MSFT_scene_understanding not loaded")
+}
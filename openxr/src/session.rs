@@ -1,5 +1,10 @@
 use std::mem::MaybeUninit;
-use std::{marker::PhantomData, ptr, sync::Arc};
+use std::{
+    ffi::CString,
+    marker::PhantomData,
+    ptr,
+    sync::{Arc, Mutex},
+};
 
 use crate::*;
 
@@ -25,6 +30,7 @@ impl<G: Graphics> Session<G> {
             inner: Arc::new(SessionInner {
                 instance: instance.clone(),
                 handle,
+                secondary_view_configurations: Mutex::new(Vec::new()),
             }),
             _marker: PhantomData,
         };
@@ -50,7 +56,73 @@ impl<G: Graphics> Session<G> {
     /// Set the debug name of this `Session`, if `XR_EXT_debug_utils` is loaded
     #[inline]
     pub fn set_name(&mut self, name: &str) -> Result<()> {
-        self.instance().set_name_raw(self.as_raw().into_raw(), name)
+        self.instance()
+            .set_name_raw(ObjectType::SESSION, self.as_raw().into_raw(), name)
+    }
+
+    /// Open a region of work labeled `name`, shown by capture tools until the matching
+    /// `end_debug_utils_label_region`, if `XR_EXT_debug_utils` is loaded
+    ///
+    /// Regions may be nested. Opening a region implicitly ends any label inserted by
+    /// `insert_debug_utils_label` in the enclosing region. Fails with `ERROR_VALIDATION_FAILURE`
+    /// if `name` contains a null byte.
+    #[inline]
+    pub fn begin_debug_utils_label_region(&self, name: &str) -> Result<()> {
+        if let Some(fp) = self.instance().exts().ext_debug_utils.as_ref() {
+            let name = CString::new(name)
+                .map_err(|_| error(None, sys::Result::ERROR_VALIDATION_FAILURE))?;
+            let label = sys::DebugUtilsLabelEXT {
+                ty: sys::DebugUtilsLabelEXT::TYPE,
+                next: ptr::null(),
+                label_name: name.as_ptr(),
+            };
+            unsafe {
+                cvt(
+                    "xrSessionBeginDebugUtilsLabelRegionEXT",
+                    (fp.session_begin_debug_utils_label_region)(self.as_raw(), &label),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Close the innermost region opened by `begin_debug_utils_label_region`, if
+    /// `XR_EXT_debug_utils` is loaded
+    #[inline]
+    pub fn end_debug_utils_label_region(&self) -> Result<()> {
+        if let Some(fp) = self.instance().exts().ext_debug_utils.as_ref() {
+            unsafe {
+                cvt(
+                    "xrSessionEndDebugUtilsLabelRegionEXT",
+                    (fp.session_end_debug_utils_label_region)(self.as_raw()),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Label the work following this call with `name`, until the next label or the end of the
+    /// enclosing region, if `XR_EXT_debug_utils` is loaded
+    ///
+    /// Fails with `ERROR_VALIDATION_FAILURE` if `name` contains a null byte.
+    #[inline]
+    pub fn insert_debug_utils_label(&self, name: &str) -> Result<()> {
+        if let Some(fp) = self.instance().exts().ext_debug_utils.as_ref() {
+            let name = CString::new(name)
+                .map_err(|_| error(None, sys::Result::ERROR_VALIDATION_FAILURE))?;
+            let label = sys::DebugUtilsLabelEXT {
+                ty: sys::DebugUtilsLabelEXT::TYPE,
+                next: ptr::null(),
+                label_name: name.as_ptr(),
+            };
+            unsafe {
+                cvt(
+                    "xrSessionInsertDebugUtilsLabelEXT",
+                    (fp.session_insert_debug_utils_label)(self.as_raw(), &label),
+                )?;
+            }
+        }
+        Ok(())
     }
 
     /// Request that the runtime show the application's rendered output to the user
@@ -61,14 +133,69 @@ impl<G: Graphics> Session<G> {
             next: ptr::null(),
             primary_view_configuration_type: ty,
         };
-        unsafe { cvt((self.fp().begin_session)(self.as_raw(), &info)) }
+        let result = unsafe {
+            cvt(
+                "xrBeginSession",
+                (self.fp().begin_session)(self.as_raw(), &info),
+            )?
+        };
+        self.inner
+            .secondary_view_configurations
+            .lock()
+            .unwrap()
+            .clear();
+        Ok(result)
+    }
+
+    /// Like `begin`, but also enable the secondary view configurations `secondary`
+    ///
+    /// Requires `XR_MSFT_secondary_view_configuration`. While the session is running,
+    /// `FrameWaiter::wait_secondary` reports whether each secondary view configuration is active, and layers
+    /// for them may be submitted with `FrameStream::end_secondary`.
+    #[inline]
+    pub fn begin_with_secondary(
+        &self,
+        ty: ViewConfigurationType,
+        secondary: &[ViewConfigurationType],
+    ) -> Result<sys::Result> {
+        if self
+            .instance()
+            .exts()
+            .msft_secondary_view_configuration
+            .is_none()
+        {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        assert!(secondary.len() <= u32::max_value() as usize);
+        let secondary_info = sys::SecondaryViewConfigurationSessionBeginInfoMSFT {
+            ty: sys::SecondaryViewConfigurationSessionBeginInfoMSFT::TYPE,
+            next: ptr::null(),
+            view_configuration_count: secondary.len() as u32,
+            enabled_view_configuration_types: secondary.as_ptr(),
+        };
+        let info = sys::SessionBeginInfo {
+            ty: sys::SessionBeginInfo::TYPE,
+            next: &secondary_info as *const _ as _,
+            primary_view_configuration_type: ty,
+        };
+        let result = unsafe {
+            cvt(
+                "xrBeginSession",
+                (self.fp().begin_session)(self.as_raw(), &info),
+            )?
+        };
+        *self.inner.secondary_view_configurations.lock().unwrap() = secondary.to_vec();
+        Ok(result)
     }
 
     /// Request a transition to `SessionState::STOPPING` so that `end` may be called.
     #[inline]
     pub fn request_exit(&self) -> Result<()> {
         unsafe {
-            cvt((self.fp().request_exit_session)(self.as_raw()))?;
+            cvt(
+                "xrRequestExitSession",
+                (self.fp().request_exit_session)(self.as_raw()),
+            )?;
         }
         Ok(())
     }
@@ -78,18 +205,17 @@ impl<G: Graphics> Session<G> {
     /// See `request_exit` for active sessions.
     #[inline]
     pub fn end(&self) -> Result<sys::Result> {
-        unsafe { cvt((self.fp().end_session)(self.as_raw())) }
+        unsafe { cvt("xrEndSession", (self.fp().end_session)(self.as_raw())) }
     }
 
     #[inline]
     pub fn reference_space_bounds_rect(&self, ty: ReferenceSpaceType) -> Result<Option<Extent2Df>> {
         unsafe {
             let mut out = MaybeUninit::uninit();
-            let status = cvt((self.fp().get_reference_space_bounds_rect)(
-                self.as_raw(),
-                ty,
-                out.as_mut_ptr(),
-            ))?;
+            let status = cvt(
+                "xrGetReferenceSpaceBoundsRect",
+                (self.fp().get_reference_space_bounds_rect)(self.as_raw(), ty, out.as_mut_ptr()),
+            )?;
             Ok(if status == sys::Result::SPACE_BOUNDS_UNAVAILABLE {
                 None
             } else {
@@ -100,15 +226,527 @@ impl<G: Graphics> Session<G> {
 
     /// Enumerate the set of reference space types supported for this session
     ///
-    /// Constant for the lifetime of the session.
+    /// Constant for the lifetime of the session. Includes `LOCAL_FLOOR` if the runtime implements
+    /// OpenXR 1.1 or `XR_EXT_local_floor` was enabled, in which case it may be preferred over
+    /// `STAGE` for floor-relative content that should follow the user rather than the play area:
+    ///
+    /// ```
+    /// # use openxr::ReferenceSpaceType;
+    /// # let supported = vec![ReferenceSpaceType::LOCAL, ReferenceSpaceType::LOCAL_FLOOR_EXT];
+    /// // `LOCAL_FLOOR_EXT` is an alias of `LOCAL_FLOOR`
+    /// let ty = if supported.contains(&ReferenceSpaceType::LOCAL_FLOOR) {
+    ///     ReferenceSpaceType::LOCAL_FLOOR
+    /// } else {
+    ///     ReferenceSpaceType::STAGE
+    /// };
+    /// assert_eq!(format!("{:?}", ty), "LOCAL_FLOOR");
+    /// ```
     #[inline]
     pub fn enumerate_reference_spaces(&self) -> Result<Vec<ReferenceSpaceType>> {
-        get_arr(|cap, count, buf| unsafe {
+        get_arr_trusted("xrEnumerateReferenceSpaces", |cap, count, buf| unsafe {
             (self.fp().enumerate_reference_spaces)(self.as_raw(), cap, count, buf)
         })
     }
 
+    /// Create a `HandTracker` for `hand`
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_EXT_hand_tracking` was not enabled on the
+    /// instance.
+    pub fn create_hand_tracker(&self, hand: HandEXT) -> Result<HandTracker> {
+        let fp = self
+            .instance()
+            .exts()
+            .ext_hand_tracking
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::HandTrackerCreateInfoEXT {
+            ty: sys::HandTrackerCreateInfoEXT::TYPE,
+            next: ptr::null(),
+            hand,
+            hand_joint_set: HandJointSetEXT::DEFAULT,
+        };
+        let mut out = sys::HandTrackerEXT::NULL;
+        unsafe {
+            cvt(
+                "xrCreateHandTrackerEXT",
+                (fp.create_hand_tracker)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(HandTracker::from_raw(self.clone(), out))
+        }
+    }
+
+    /// Create a `BodyTracker`
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_body_tracking` was not enabled on the
+    /// instance.
+    pub fn create_body_tracker(&self) -> Result<BodyTracker> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_body_tracking
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::BodyTrackerCreateInfoFB {
+            ty: sys::BodyTrackerCreateInfoFB::TYPE,
+            next: ptr::null(),
+            body_joint_set: BodyJointSetFB::DEFAULT,
+        };
+        let mut out = sys::BodyTrackerFB::NULL;
+        unsafe {
+            cvt(
+                "xrCreateBodyTrackerFB",
+                (fp.create_body_tracker)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(BodyTracker::from_raw(self.clone(), out))
+        }
+    }
+
+    /// Create an `EyeTracker`
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_eye_tracking_social` was not enabled on the
+    /// instance.
+    pub fn create_eye_tracker(&self) -> Result<EyeTracker> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_eye_tracking_social
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::EyeTrackerCreateInfoFB {
+            ty: sys::EyeTrackerCreateInfoFB::TYPE,
+            next: ptr::null(),
+        };
+        let mut out = sys::EyeTrackerFB::NULL;
+        unsafe {
+            cvt(
+                "xrCreateEyeTrackerFB",
+                (fp.create_eye_tracker)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(EyeTracker::from_raw(self.clone(), out))
+        }
+    }
+
+    /// Create a `FaceTracker2` fed by the `visual` and/or `audio` data sources
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_face_tracking2` was not enabled on the
+    /// instance.
+    pub fn create_face_tracker2(&self, visual: bool, audio: bool) -> Result<FaceTracker2> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_face_tracking2
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let mut sources = Vec::with_capacity(2);
+        if visual {
+            sources.push(FaceTrackingDataSource2FB::VISUAL);
+        }
+        if audio {
+            sources.push(FaceTrackingDataSource2FB::AUDIO);
+        }
+        let info = sys::FaceTrackerCreateInfo2FB {
+            ty: sys::FaceTrackerCreateInfo2FB::TYPE,
+            next: ptr::null(),
+            face_expression_set: FaceExpressionSet2FB::DEFAULT,
+            requested_data_source_count: sources.len() as u32,
+            requested_data_sources: sources.as_mut_ptr(),
+        };
+        let mut out = sys::FaceTracker2FB::NULL;
+        unsafe {
+            cvt(
+                "xrCreateFaceTracker2FB",
+                (fp.create_face_tracker2)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(FaceTracker2::from_raw(self.clone(), out))
+        }
+    }
+
+    /// Create a `FacialTracker` observing the expressions selected by `tracking_type`
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_HTC_facial_tracking` was not enabled on the
+    /// instance.
+    pub fn create_facial_tracker(
+        &self,
+        tracking_type: FacialTrackingTypeHTC,
+    ) -> Result<FacialTracker> {
+        let fp = self
+            .instance()
+            .exts()
+            .htc_facial_tracking
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::FacialTrackerCreateInfoHTC {
+            ty: sys::FacialTrackerCreateInfoHTC::TYPE,
+            next: ptr::null(),
+            facial_tracking_type: tracking_type,
+        };
+        let mut out = sys::FacialTrackerHTC::NULL;
+        unsafe {
+            cvt(
+                "xrCreateFacialTrackerHTC",
+                (fp.create_facial_tracker)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(FacialTracker::from_raw(self.clone(), out, tracking_type))
+        }
+    }
+
+    /// Create a `FoveationProfile` from foveation `settings`
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` unless both `XR_FB_foveation` and
+    /// `XR_FB_foveation_configuration` were enabled on the instance, as well as
+    /// `XR_META_foveation_eye_tracked` if `settings.eye_tracked` is set.
+    pub fn create_foveation_profile(
+        &self,
+        settings: &FoveationLevelProfile,
+    ) -> Result<FoveationProfile> {
+        let exts = self.instance().exts();
+        let fp = match (exts.fb_foveation.as_ref(), exts.fb_foveation_configuration) {
+            (Some(fp), Some(_)) => fp,
+            _ => return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT)),
+        };
+        if settings.eye_tracked && exts.meta_foveation_eye_tracked.is_none() {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        let eye_tracked = sys::FoveationEyeTrackedProfileCreateInfoMETA {
+            ty: sys::FoveationEyeTrackedProfileCreateInfoMETA::TYPE,
+            next: ptr::null(),
+            flags: FoveationEyeTrackedProfileCreateFlagsMETA::EMPTY,
+        };
+        let mut level = sys::FoveationLevelProfileCreateInfoFB {
+            ty: sys::FoveationLevelProfileCreateInfoFB::TYPE,
+            next: if settings.eye_tracked {
+                &eye_tracked as *const _ as _
+            } else {
+                ptr::null_mut()
+            },
+            level: settings.level,
+            vertical_offset: settings.vertical_offset,
+            dynamic: settings.dynamic,
+        };
+        let info = sys::FoveationProfileCreateInfoFB {
+            ty: sys::FoveationProfileCreateInfoFB::TYPE,
+            next: &mut level as *mut _ as _,
+        };
+        let mut out = sys::FoveationProfileFB::NULL;
+        unsafe {
+            cvt(
+                "xrCreateFoveationProfileFB",
+                (fp.create_foveation_profile)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(FoveationProfile::from_raw(self.clone(), out))
+        }
+    }
+
+    /// Get the current center of eye-tracked foveation
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_META_foveation_eye_tracked` was not enabled on
+    /// the instance.
+    pub fn get_foveation_eye_tracked_state(&self) -> Result<FoveationEyeTrackedState> {
+        let fp = self
+            .instance()
+            .exts()
+            .meta_foveation_eye_tracked
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let mut out = sys::FoveationEyeTrackedStateMETA::out(ptr::null_mut());
+        unsafe {
+            cvt(
+                "xrGetFoveationEyeTrackedStateMETA",
+                (fp.get_foveation_eye_tracked_state)(self.as_raw(), out.as_mut_ptr()),
+            )?;
+            let out = out.assume_init();
+            Ok(FoveationEyeTrackedState {
+                foveation_center: out.foveation_center,
+                flags: out.flags,
+            })
+        }
+    }
+
+    /// Create the `Passthrough` feature for this session
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_passthrough` was not enabled on the
+    /// instance.
+    pub fn create_passthrough(&self, flags: PassthroughFlagsFB) -> Result<Passthrough> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_passthrough
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::PassthroughCreateInfoFB {
+            ty: sys::PassthroughCreateInfoFB::TYPE,
+            next: ptr::null(),
+            flags,
+        };
+        let mut out = sys::PassthroughFB::NULL;
+        unsafe {
+            cvt(
+                "xrCreatePassthroughFB",
+                (fp.create_passthrough)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(Passthrough::from_raw(self.clone(), out))
+        }
+    }
+
+    /// Create a `SpatialAnchor` at `pose` in `space` at `time`
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_MSFT_spatial_anchor` was not enabled on the
+    /// instance.
+    pub fn create_spatial_anchor(
+        &self,
+        space: &Space,
+        pose: Posef,
+        time: Time,
+    ) -> Result<SpatialAnchor> {
+        let fp = self
+            .instance()
+            .exts()
+            .msft_spatial_anchor
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::SpatialAnchorCreateInfoMSFT {
+            ty: sys::SpatialAnchorCreateInfoMSFT::TYPE,
+            next: ptr::null(),
+            space: space.as_raw(),
+            pose,
+            time,
+        };
+        let mut out = sys::SpatialAnchorMSFT::NULL;
+        unsafe {
+            cvt(
+                "xrCreateSpatialAnchorMSFT",
+                (fp.create_spatial_anchor)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(SpatialAnchor::from_raw(self.clone(), out))
+        }
+    }
+
+    /// Create a `TriangleMesh` from a triangle list
+    ///
+    /// If `flags` contains `TriangleMeshFlagsFB::MUTABLE`, the lengths of `vertices` and `indices`
+    /// also fix the capacity available to `TriangleMesh::update`. Returns
+    /// `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_triangle_mesh` was not enabled on the instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` does not describe whole triangles.
+    pub fn create_triangle_mesh(
+        &self,
+        flags: TriangleMeshFlagsFB,
+        winding_order: WindingOrderFB,
+        vertices: &[Vector3f],
+        indices: &[u32],
+    ) -> Result<TriangleMesh> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_triangle_mesh
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        assert_eq!(
+            indices.len() % 3,
+            0,
+            "`indices` must describe whole triangles"
+        );
+        let mutable = flags.contains(TriangleMeshFlagsFB::MUTABLE);
+        let info = sys::TriangleMeshCreateInfoFB {
+            ty: sys::TriangleMeshCreateInfoFB::TYPE,
+            next: ptr::null(),
+            flags,
+            winding_order,
+            vertex_count: vertices.len() as u32,
+            // Mutable meshes are allocated empty and filled by `init_mutable`
+            vertex_buffer: if mutable {
+                ptr::null()
+            } else {
+                vertices.as_ptr()
+            },
+            triangle_count: (indices.len() / 3) as u32,
+            index_buffer: if mutable {
+                ptr::null()
+            } else {
+                indices.as_ptr()
+            },
+        };
+        let mut out = sys::TriangleMeshFB::NULL;
+        unsafe {
+            cvt(
+                "xrCreateTriangleMeshFB",
+                (fp.create_triangle_mesh)(self.as_raw(), &info, &mut out),
+            )?;
+            let mut mesh = TriangleMesh::from_raw(self.clone(), out);
+            if mutable {
+                mesh.init_mutable(vertices, indices)?;
+            }
+            Ok(mesh)
+        }
+    }
+
+    /// Begin creating a persistable spatial anchor at `pose` in `space` at `time`
+    ///
+    /// Completion is reported by `Event::SpatialAnchorCreateCompleteFB` carrying the returned
+    /// request ID, whose space may be wrapped with `Space::reference_from_raw`. Returns
+    /// `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_spatial_entity` was not enabled on the instance.
+    pub fn create_spatial_anchor_fb(
+        &self,
+        space: &Space,
+        pose: Posef,
+        time: Time,
+    ) -> Result<AsyncRequestIdFB> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_spatial_entity
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::SpatialAnchorCreateInfoFB {
+            ty: sys::SpatialAnchorCreateInfoFB::TYPE,
+            next: ptr::null(),
+            space: space.as_raw(),
+            pose_in_space: pose,
+            time,
+        };
+        let mut out = AsyncRequestIdFB::default();
+        unsafe {
+            cvt(
+                "xrCreateSpatialAnchorFB",
+                (fp.create_spatial_anchor)(self.as_raw(), &info, &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Begin querying for up to `max_result_count` spaces matching `filter` in `location`
+    ///
+    /// Batches of results are announced by `Event::SpaceQueryResultsAvailableFB` and should be
+    /// fetched with `retrieve_space_query_results`; `Event::SpaceQueryCompleteFB` reports the end
+    /// of the query. Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_spatial_entity_query` was not
+    /// enabled on the instance.
+    pub fn query_spaces(
+        &self,
+        filter: SpaceFilter<'_>,
+        location: SpaceStorageLocationFB,
+        max_result_count: u32,
+        timeout: Duration,
+    ) -> Result<AsyncRequestIdFB> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_spatial_entity_query
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let location = sys::SpaceStorageLocationFilterInfoFB {
+            ty: sys::SpaceStorageLocationFilterInfoFB::TYPE,
+            next: ptr::null(),
+            location,
+        };
+        let uuid_filter;
+        let component_filter;
+        let filter: *const sys::SpaceFilterInfoBaseHeaderFB = match filter {
+            SpaceFilter::Uuids(uuids) => {
+                uuid_filter = sys::SpaceUuidFilterInfoFB {
+                    ty: sys::SpaceUuidFilterInfoFB::TYPE,
+                    next: &location as *const _ as _,
+                    uuid_count: uuids.len() as u32,
+                    // Never written through by the runtime
+                    uuids: uuids.as_ptr() as *mut _,
+                };
+                &uuid_filter as *const _ as _
+            }
+            SpaceFilter::Component(component_type) => {
+                component_filter = sys::SpaceComponentFilterInfoFB {
+                    ty: sys::SpaceComponentFilterInfoFB::TYPE,
+                    next: &location as *const _ as _,
+                    component_type,
+                };
+                &component_filter as *const _ as _
+            }
+        };
+        let info = sys::SpaceQueryInfoFB {
+            ty: sys::SpaceQueryInfoFB::TYPE,
+            next: ptr::null(),
+            query_action: SpaceQueryActionFB::LOAD,
+            max_result_count,
+            timeout,
+            filter,
+            exclude_filter: ptr::null(),
+        };
+        let mut out = AsyncRequestIdFB::default();
+        unsafe {
+            cvt(
+                "xrQuerySpacesFB",
+                (fp.query_spaces)(self.as_raw(), &info as *const _ as _, &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Fetch the spaces found by the query `request_id` since the last call
+    pub fn retrieve_space_query_results(
+        &self,
+        request_id: AsyncRequestIdFB,
+    ) -> Result<Vec<SpaceQueryResult>> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_spatial_entity_query
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let results = get_arr(
+            "xrRetrieveSpaceQueryResultsFB",
+            |capacity, count, buf| unsafe {
+                let mut results = sys::SpaceQueryResultsFB {
+                    ty: sys::SpaceQueryResultsFB::TYPE,
+                    next: ptr::null_mut(),
+                    result_capacity_input: capacity,
+                    result_count_output: 0,
+                    results: buf,
+                };
+                let status =
+                    (fp.retrieve_space_query_results)(self.as_raw(), request_id, &mut results);
+                *count = results.result_count_output;
+                status
+            },
+        )?;
+        Ok(results
+            .into_iter()
+            .map(|x| SpaceQueryResult {
+                space: unsafe { Space::reference_from_raw(self.clone(), x.space) },
+                uuid: x.uuid,
+            })
+            .collect())
+    }
+
+    /// Create a `SceneObserver` for computing models of the physical environment
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_MSFT_scene_understanding` was not enabled on
+    /// the instance.
+    pub fn create_scene_observer(&self) -> Result<SceneObserver> {
+        let fp = self
+            .instance()
+            .exts()
+            .msft_scene_understanding
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::SceneObserverCreateInfoMSFT {
+            ty: sys::SceneObserverCreateInfoMSFT::TYPE,
+            next: ptr::null(),
+        };
+        let mut out = sys::SceneObserverMSFT::NULL;
+        unsafe {
+            cvt(
+                "xrCreateSceneObserverMSFT",
+                (fp.create_scene_observer)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(SceneObserver::from_raw(self.clone(), out))
+        }
+    }
+
     /// Creates a `Space` based on a chosen reference space
+    ///
+    /// OpenXR has no call to recenter a space. Instead, when the user recenters through the
+    /// runtime, `LOCAL`, `LOCAL_FLOOR`, and possibly `STAGE` spaces move, and an
+    /// `Event::ReferenceSpaceChangePending` reports when, and by how much if `pose_valid`.
     pub fn create_reference_space(
         &self,
         reference_space_type: ReferenceSpaceType,
@@ -122,11 +760,72 @@ impl<G: Graphics> Session<G> {
         };
         let mut out = sys::Space::NULL;
         unsafe {
-            cvt((self.fp().create_reference_space)(
-                self.as_raw(),
-                &info,
-                &mut out,
-            ))?;
+            cvt(
+                "xrCreateReferenceSpace",
+                (self.fp().create_reference_space)(self.as_raw(), &info, &mut out),
+            )?;
+            Ok(Space::reference_from_raw(self.clone(), out))
+        }
+    }
+
+    /// Find the physical keyboard tracked by the system, if any, matching `flags`
+    ///
+    /// `flags` should contain either `LOCAL` or `REMOTE`. Returns `None` if no such keyboard is
+    /// tracked. Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_keyboard_tracking` was not enabled
+    /// on the instance.
+    pub fn query_system_tracked_keyboard(
+        &self,
+        flags: KeyboardTrackingQueryFlagsFB,
+    ) -> Result<Option<sys::KeyboardTrackingDescriptionFB>> {
+        let fp = self.keyboard_tracking_fp()?;
+        let info = sys::KeyboardTrackingQueryFB {
+            ty: sys::KeyboardTrackingQueryFB::TYPE,
+            next: ptr::null_mut(),
+            flags,
+        };
+        let mut out = sys::KeyboardTrackingDescriptionFB {
+            tracked_keyboard_id: 0,
+            size: Vector3f {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            flags: KeyboardTrackingFlagsFB::EMPTY,
+            name: [0; sys::MAX_KEYBOARD_TRACKING_NAME_SIZE_FB],
+        };
+        unsafe {
+            cvt(
+                "xrQuerySystemTrackedKeyboardFB",
+                (fp.query_system_tracked_keyboard)(self.as_raw(), &info, &mut out),
+            )?;
+        }
+        Ok(if out.flags.contains(KeyboardTrackingFlagsFB::EXISTS) {
+            Some(out)
+        } else {
+            None
+        })
+    }
+
+    /// Create a `Space` tracking the keyboard described by `description`
+    ///
+    /// The space follows the keyboard, whose bounding box has extents `description.size`. Returns
+    /// `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_keyboard_tracking` was not enabled on the instance.
+    pub fn create_keyboard_space(
+        &self,
+        description: &sys::KeyboardTrackingDescriptionFB,
+    ) -> Result<Space> {
+        let fp = self.keyboard_tracking_fp()?;
+        let info = sys::KeyboardSpaceCreateInfoFB {
+            ty: sys::KeyboardSpaceCreateInfoFB::TYPE,
+            next: ptr::null_mut(),
+            tracked_keyboard_id: description.tracked_keyboard_id,
+        };
+        let mut out = sys::Space::NULL;
+        unsafe {
+            cvt(
+                "xrCreateKeyboardSpaceFB",
+                (fp.create_keyboard_space)(self.as_raw(), &info, &mut out),
+            )?;
             Ok(Space::reference_from_raw(self.clone(), out))
         }
     }
@@ -137,10 +836,13 @@ impl<G: Graphics> Session<G> {
     /// created.
     #[inline]
     pub fn enumerate_swapchain_formats(&self) -> Result<Vec<G::Format>> {
-        let raw = get_arr(|capacity, count, buf| unsafe {
-            (self.fp().enumerate_swapchain_formats)(self.as_raw(), capacity, count, buf)
-        })?;
-        Ok(raw.into_iter().map(G::raise_format).collect())
+        let raw = get_arr_trusted(
+            "xrEnumerateSwapchainFormats",
+            |capacity, count, buf| unsafe {
+                (self.fp().enumerate_swapchain_formats)(self.as_raw(), capacity, count, buf)
+            },
+        )?;
+        Ok(raw.into_iter().filter_map(G::raise_format).collect())
     }
 
     #[inline]
@@ -159,49 +861,270 @@ impl<G: Graphics> Session<G> {
             array_size: info.array_size,
             mip_count: info.mip_count,
         };
+        let mut swapchain = unsafe {
+            cvt(
+                "xrCreateSwapchain",
+                (self.fp().create_swapchain)(self.as_raw(), &info, &mut out),
+            )?;
+            Swapchain::from_raw(self.clone(), out)
+        };
+        swapchain.params = Some(SwapchainParams {
+            format: info.format,
+            sample_count: info.sample_count,
+            width: info.width,
+            height: info.height,
+            face_count: info.face_count,
+            array_size: info.array_size,
+            mip_count: info.mip_count,
+        });
+        Ok(swapchain)
+    }
+
+    /// Create a swapchain suitable for rendering color for a stereo view configuration
+    ///
+    /// The swapchain has one sample, face, and mip level, and two array layers, one per eye. Use
+    /// `create_swapchain` for anything else.
+    #[inline]
+    pub fn create_color_swapchain(
+        &self,
+        width: u32,
+        height: u32,
+        format: G::Format,
+    ) -> Result<Swapchain<G>> {
+        self.create_stereo_swapchain(width, height, format, SwapchainUsageFlags::COLOR_ATTACHMENT)
+    }
+
+    /// Create a swapchain suitable for rendering depth for a stereo view configuration
+    ///
+    /// Like `create_color_swapchain`, but for use as a depth/stencil attachment. `format` should be
+    /// a depth format supported by `enumerate_swapchain_formats`.
+    #[inline]
+    pub fn create_depth_swapchain(
+        &self,
+        width: u32,
+        height: u32,
+        format: G::Format,
+    ) -> Result<Swapchain<G>> {
+        self.create_stereo_swapchain(
+            width,
+            height,
+            format,
+            SwapchainUsageFlags::DEPTH_STENCIL_ATTACHMENT,
+        )
+    }
+
+    // Private helper
+    #[inline]
+    fn create_stereo_swapchain(
+        &self,
+        width: u32,
+        height: u32,
+        format: G::Format,
+        usage_flags: SwapchainUsageFlags,
+    ) -> Result<Swapchain<G>> {
+        self.create_swapchain(&SwapchainCreateInfo {
+            create_flags: SwapchainCreateFlags::EMPTY,
+            usage_flags,
+            format,
+            sample_count: 1,
+            width,
+            height,
+            face_count: 1,
+            array_size: 2,
+            mip_count: 1,
+        })
+    }
+
+    /// Determine the locations of many spaces relative to `base` at a specified time in one call
+    ///
+    /// Equivalent to calling `Space::locate` for each of `spaces`, but cheaper when locating many
+    /// spaces at once. Requires `XR_KHR_locate_spaces`; the equivalent core OpenXR 1.1 function is
+    /// not used, as only the 1.0 core API is targeted.
+    #[inline]
+    pub fn locate_spaces(
+        &self,
+        base: &Space,
+        time: Time,
+        spaces: &[&Space],
+    ) -> Result<Vec<SpaceLocation>> {
+        let fp = self
+            .instance()
+            .exts()
+            .khr_locate_spaces
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        // This assert allows this function to be safe.
+        for space in spaces.iter().cloned().chain(Some(base)) {
+            assert!(
+                Arc::ptr_eq(&self.inner, &space.session),
+                "all spaces must have been created, allocated, or retrieved from `self`"
+            );
+        }
+        assert!(spaces.len() <= u32::max_value() as usize);
+        let handles = spaces.iter().map(|x| x.as_raw()).collect::<Vec<_>>();
+        let info = sys::SpacesLocateInfoKHR {
+            ty: sys::SpacesLocateInfoKHR::TYPE,
+            next: ptr::null(),
+            base_space: base.as_raw(),
+            time,
+            space_count: handles.len() as u32,
+            spaces: handles.as_ptr(),
+        };
+        let mut locations = Vec::<sys::SpaceLocationData>::with_capacity(handles.len());
+        let mut out = sys::SpaceLocationsKHR {
+            ty: sys::SpaceLocationsKHR::TYPE,
+            next: ptr::null_mut(),
+            location_count: handles.len() as u32,
+            locations: locations.as_mut_ptr(),
+        };
         unsafe {
-            cvt((self.fp().create_swapchain)(self.as_raw(), &info, &mut out))?;
-            Ok(Swapchain::from_raw(self.clone(), out))
+            cvt(
+                "xrLocateSpacesKHR",
+                (fp.locate_spaces)(self.as_raw(), &info, &mut out),
+            )?;
+            locations.set_len(handles.len());
         }
+        Ok(locations
+            .into_iter()
+            .map(|x| SpaceLocation {
+                location_flags: x.location_flags,
+                pose: x.pose,
+            })
+            .collect())
     }
 
     /// Get the view and projection info for a particular display time
     ///
     /// When rendering, this should be called as late as possible before the GPU accesses it to
-    /// provide the most accurate possible poses.
+    /// provide the most accurate possible poses. One `View` is returned for each view in the
+    /// configuration, e.g. four for `ViewConfigurationType::PRIMARY_QUAD_VARJO`.
     #[inline]
     pub fn locate_views(
         &self,
         view_configuration_type: ViewConfigurationType,
         display_time: Time,
         space: &Space,
+    ) -> Result<(ViewStateFlags, Vec<View>)> {
+        unsafe {
+            self.locate_views_chained(
+                view_configuration_type,
+                display_time,
+                space,
+                ptr::null(),
+                ptr::null_mut(),
+                |_| ptr::null_mut(),
+            )
+        }
+    }
+
+    /// Like `locate_views`, but specifying whether foveated rendering is active
+    ///
+    /// When `foveated_rendering_active` is true, the focus views of
+    /// `ViewConfigurationType::PRIMARY_QUAD_VARJO` follow the user's gaze. Returns
+    /// `ERROR_EXTENSION_NOT_PRESENT` if `XR_VARJO_foveated_rendering` was not enabled on the
+    /// instance.
+    #[inline]
+    pub fn locate_views_foveated_varjo(
+        &self,
+        view_configuration_type: ViewConfigurationType,
+        display_time: Time,
+        space: &Space,
+        foveated_rendering_active: bool,
+    ) -> Result<(ViewStateFlags, Vec<View>)> {
+        if self.instance().exts().varjo_foveated_rendering.is_none() {
+            return Err(error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT));
+        }
+        let foveated = sys::ViewLocateFoveatedRenderingVARJO {
+            ty: sys::ViewLocateFoveatedRenderingVARJO::TYPE,
+            next: ptr::null(),
+            foveated_rendering_active: foveated_rendering_active.into(),
+        };
+        unsafe {
+            self.locate_views_chained(
+                view_configuration_type,
+                display_time,
+                space,
+                &foveated as *const _ as _,
+                ptr::null_mut(),
+                |_| ptr::null_mut(),
+            )
+        }
+    }
+
+    /// Like `locate_views`, but with extension structures chained onto the input and outputs
+    ///
+    /// `next` is chained onto the `XrViewLocateInfo`, `state_next` onto the `XrViewState`, and
+    /// `view_next(i)` onto the `XrView` of the `i`th view. The chained output structures are filled
+    /// in place, and may be read once this returns. `view_next` may be called more than once for
+    /// the same view if the number of views changes during the call.
+    ///
+    /// Per-configuration details such as `XrViewConfigurationDepthRangeEXT` extend
+    /// `XrViewConfigurationView` instead, and are not reported here.
+    ///
+    /// # Safety
+    ///
+    /// Each chain must be null or a valid chain of structures extending the corresponding type.
+    pub unsafe fn locate_views_chained(
+        &self,
+        view_configuration_type: ViewConfigurationType,
+        display_time: Time,
+        space: &Space,
+        next: *const std::ffi::c_void,
+        state_next: *mut std::ffi::c_void,
+        mut view_next: impl FnMut(usize) -> *mut std::ffi::c_void,
     ) -> Result<(ViewStateFlags, Vec<View>)> {
         let info = sys::ViewLocateInfo {
             ty: sys::ViewLocateInfo::TYPE,
-            next: ptr::null(),
+            next,
             view_configuration_type,
             display_time,
             space: space.as_raw(),
         };
-        let (flags, raw) = unsafe {
-            let mut out = sys::ViewState::out(ptr::null_mut());
-            let raw = get_arr_init(sys::View::out(ptr::null_mut()), |cap, count, buf| {
+        let mut out = sys::ViewState::out(state_next as _);
+        let mut count = 0;
+        cvt(
+            "xrLocateViews",
+            (self.fp().locate_views)(
+                self.as_raw(),
+                &info,
+                out.as_mut_ptr(),
+                0,
+                &mut count,
+                ptr::null_mut(),
+            ),
+        )?;
+        // Like `get_arr_init`, but each view carries its own output chain
+        let raw = loop {
+            let mut raw = (0..count as usize)
+                .map(|i| sys::View::out(view_next(i) as _))
+                .collect::<Vec<_>>();
+            match cvt(
+                "xrLocateViews",
                 (self.fp().locate_views)(
                     self.as_raw(),
                     &info,
                     out.as_mut_ptr(),
-                    cap,
                     count,
-                    buf as _,
-                )
-            })?;
-            (out.assume_init().view_state_flags, raw)
+                    &mut count,
+                    raw.as_mut_ptr() as _,
+                ),
+            ) {
+                Ok(_) => {
+                    raw.truncate(count as usize);
+                    break raw;
+                }
+                Err(e) if e == sys::Result::ERROR_SIZE_INSUFFICIENT => {}
+                Err(e) => {
+                    return Err(e);
+                }
+            }
         };
+        let flags = out.assume_init().view_state_flags;
         Ok((
             flags,
             raw.into_iter()
                 .map(|x| {
-                    let x = unsafe { x.assume_init() };
+                    let x = x.assume_init();
                     View {
                         pose: x.pose,
                         fov: x.fov,
@@ -218,15 +1141,30 @@ impl<G: Graphics> Session<G> {
     pub fn current_interaction_profile(&self, top_level_user_path: Path) -> Result<Path> {
         unsafe {
             let mut out = sys::InteractionProfileState::out(ptr::null_mut());
-            cvt((self.fp().get_current_interaction_profile)(
-                self.as_raw(),
-                top_level_user_path,
-                out.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrGetCurrentInteractionProfile",
+                (self.fp().get_current_interaction_profile)(
+                    self.as_raw(),
+                    top_level_user_path,
+                    out.as_mut_ptr(),
+                ),
+            )?;
             Ok(out.assume_init().interaction_profile)
         }
     }
 
+    /// Get the suggested interaction profile in use for each of `top_level_user_paths`
+    ///
+    /// Entries are NULL for paths with no active profile. Profiles only change when
+    /// `Event::InteractionProfileChanged` is received, so this need not be polled every frame.
+    #[inline]
+    pub fn interaction_profiles(&self, top_level_user_paths: &[Path]) -> Result<Vec<Path>> {
+        top_level_user_paths
+            .iter()
+            .map(|&path| self.current_interaction_profile(path))
+            .collect()
+    }
+
     /// Enable use of action sets with a session
     ///
     /// Once attached, action sets become immutable.
@@ -240,7 +1178,10 @@ impl<G: Graphics> Session<G> {
             action_sets: sets.as_ptr(),
         };
         unsafe {
-            cvt((self.fp().attach_session_action_sets)(self.as_raw(), &info))?;
+            cvt(
+                "xrAttachSessionActionSets",
+                (self.fp().attach_session_action_sets)(self.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
@@ -255,7 +1196,10 @@ impl<G: Graphics> Session<G> {
             active_action_sets: action_sets.as_ptr() as _,
         };
         unsafe {
-            cvt((self.fp().sync_actions)(self.as_raw(), &info))?;
+            cvt(
+                "xrSyncActions",
+                (self.fp().sync_actions)(self.as_raw(), &info),
+            )?;
         }
         Ok(())
     }
@@ -273,14 +1217,15 @@ impl<G: Graphics> Session<G> {
             source_path: source,
             which_components,
         };
-        get_str(|cap, count, buf| unsafe {
+        get_str_trusted("xrGetInputSourceLocalizedName", |cap, count, buf| unsafe {
             (self.fp().get_input_source_localized_name)(self.as_raw(), &info, cap, count, buf)
         })
     }
 
     /// Get a mesh describing the visible area of a view
     ///
-    /// Requires KHR_visibility_mask. Useful to skip shading fragments the user can't see.
+    /// Useful to skip shading fragments the user can't see. Returns `ERROR_EXTENSION_NOT_PRESENT`
+    /// if `XR_KHR_visibility_mask` was not enabled on the instance.
     ///
     /// See also the `VisibilityMaskChangedKHR` event.
     #[inline]
@@ -290,6 +1235,7 @@ impl<G: Graphics> Session<G> {
         view_index: u32,
         visibility_mask_type: VisibilityMaskTypeKHR,
     ) -> Result<VisibilityMask> {
+        let fp = self.instance().visibility_mask()?;
         let mut info = sys::VisibilityMaskKHR {
             ty: sys::VisibilityMaskKHR::TYPE,
             next: ptr::null_mut(),
@@ -301,13 +1247,16 @@ impl<G: Graphics> Session<G> {
             indices: ptr::null_mut(),
         };
         unsafe {
-            cvt((self.instance().visibility_mask().get_visibility_mask)(
-                self.as_raw(),
-                view_configuration_type,
-                view_index,
-                visibility_mask_type,
-                &mut info,
-            ))?;
+            cvt(
+                "xrGetVisibilityMaskKHR",
+                (fp.get_visibility_mask)(
+                    self.as_raw(),
+                    view_configuration_type,
+                    view_index,
+                    visibility_mask_type,
+                    &mut info,
+                ),
+            )?;
             let mut out = VisibilityMask {
                 vertices: Vec::with_capacity(info.vertex_count_output as usize),
                 indices: Vec::with_capacity(info.index_count_output as usize),
@@ -315,19 +1264,22 @@ impl<G: Graphics> Session<G> {
             loop {
                 info.vertex_capacity_input = out.vertices.capacity() as u32;
                 info.index_capacity_input = out.indices.capacity() as u32;
-                match cvt((self.instance().visibility_mask().get_visibility_mask)(
-                    self.as_raw(),
-                    view_configuration_type,
-                    view_index,
-                    visibility_mask_type,
-                    &mut info,
-                )) {
+                match cvt(
+                    "xrGetVisibilityMaskKHR",
+                    (fp.get_visibility_mask)(
+                        self.as_raw(),
+                        view_configuration_type,
+                        view_index,
+                        visibility_mask_type,
+                        &mut info,
+                    ),
+                ) {
                     Ok(_) => {
                         out.vertices.set_len(info.vertex_count_output as usize);
                         out.indices.set_len(info.index_count_output as usize);
                         return Ok(out);
                     }
-                    Err(sys::Result::ERROR_SIZE_INSUFFICIENT) => {
+                    Err(e) if e == sys::Result::ERROR_SIZE_INSUFFICIENT => {
                         out.vertices.reserve(
                             (info.vertex_count_output as usize)
                                 .saturating_sub(out.vertices.capacity()),
@@ -345,6 +1297,163 @@ impl<G: Graphics> Session<G> {
         }
     }
 
+    /// Hint the desired performance level of a processing domain to the runtime
+    ///
+    /// Changes in the runtime's own assessment of each domain are reported by the
+    /// `PerfSettingsEXT` event. Returns `ERROR_EXTENSION_NOT_PRESENT` if
+    /// `XR_EXT_performance_settings` was not enabled on the instance.
+    #[inline]
+    pub fn set_performance_level(
+        &self,
+        domain: PerfSettingsDomainEXT,
+        level: PerfSettingsLevelEXT,
+    ) -> Result<()> {
+        let fp = self
+            .instance()
+            .exts()
+            .ext_performance_settings
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        unsafe {
+            cvt(
+                "xrPerfSettingsSetPerformanceLevelEXT",
+                (fp.perf_settings_set_performance_level)(self.as_raw(), domain, level),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Enumerate the display refresh rates supported by the current session, in Hz
+    ///
+    /// Requires `XR_FB_display_refresh_rate`.
+    #[inline]
+    pub fn enumerate_display_refresh_rates(&self) -> Result<Vec<f32>> {
+        let fp = self.display_refresh_rate_fp()?;
+        get_arr_trusted(
+            "xrEnumerateDisplayRefreshRatesFB",
+            |capacity, count, buf| unsafe {
+                (fp.enumerate_display_refresh_rates)(self.as_raw(), capacity, count, buf)
+            },
+        )
+    }
+
+    /// Get the current display refresh rate, in Hz
+    ///
+    /// Requires `XR_FB_display_refresh_rate`.
+    #[inline]
+    pub fn display_refresh_rate(&self) -> Result<f32> {
+        let fp = self.display_refresh_rate_fp()?;
+        let mut out = 0.0;
+        unsafe {
+            cvt(
+                "xrGetDisplayRefreshRateFB",
+                (fp.get_display_refresh_rate)(self.as_raw(), &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Request a display refresh rate, in Hz, from those returned by
+    /// `enumerate_display_refresh_rates`
+    ///
+    /// A `rate` of `0.0` selects the runtime's default. The change takes effect asynchronously, as
+    /// signaled by the `DisplayRefreshRateChangedFB` event. Requires `XR_FB_display_refresh_rate`.
+    #[inline]
+    pub fn request_display_refresh_rate(&self, rate: f32) -> Result<()> {
+        let fp = self.display_refresh_rate_fp()?;
+        unsafe {
+            cvt(
+                "xrRequestDisplayRefreshRateFB",
+                (fp.request_display_refresh_rate)(self.as_raw(), rate),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Enumerate the color spaces supported by the current session
+    ///
+    /// Requires `XR_FB_color_space`.
+    #[inline]
+    pub fn enumerate_color_spaces(&self) -> Result<Vec<ColorSpaceFB>> {
+        let fp = self.color_space_fp()?;
+        get_arr_trusted("xrEnumerateColorSpacesFB", |capacity, count, buf| unsafe {
+            (fp.enumerate_color_spaces)(self.as_raw(), capacity, count, buf)
+        })
+    }
+
+    /// Set the color space in which the application's rendered output is interpreted
+    ///
+    /// `SystemProperties::color_space` reports the display's native color space. Requires
+    /// `XR_FB_color_space`.
+    #[inline]
+    pub fn set_color_space(&self, space: ColorSpaceFB) -> Result<()> {
+        let fp = self.color_space_fp()?;
+        unsafe {
+            cvt(
+                "xrSetColorSpaceFB",
+                (fp.set_color_space)(self.as_raw(), space),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Inform the runtime that the thread `thread_id`, as returned by `gettid`, performs the given
+    /// kind of work, allowing it to be scheduled appropriately
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_KHR_android_thread_settings` was not enabled
+    /// on the instance.
+    #[cfg(target_os = "android")]
+    #[inline]
+    pub fn set_android_application_thread(
+        &self,
+        thread_type: AndroidThreadTypeKHR,
+        thread_id: u32,
+    ) -> Result<()> {
+        let fp = self
+            .instance()
+            .exts()
+            .khr_android_thread_settings
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        unsafe {
+            cvt(
+                "xrSetAndroidApplicationThreadKHR",
+                (fp.set_android_application_thread)(self.as_raw(), thread_type, thread_id),
+            )?;
+        }
+        Ok(())
+    }
+
+    // Private helper
+    #[inline]
+    fn display_refresh_rate_fp(&self) -> Result<&raw::DisplayRefreshRateFB> {
+        self.instance()
+            .exts()
+            .fb_display_refresh_rate
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+
+    // Private helper
+    #[inline]
+    fn keyboard_tracking_fp(&self) -> Result<&raw::KeyboardTrackingFB> {
+        self.instance()
+            .exts()
+            .fb_keyboard_tracking
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+
+    // Private helper
+    #[inline]
+    fn color_space_fp(&self) -> Result<&raw::ColorSpaceFB> {
+        self.instance()
+            .exts()
+            .fb_color_space
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+
     // Private helper
     #[inline]
     fn fp(&self) -> &raw::Instance {
@@ -366,6 +1475,7 @@ pub struct VisibilityMask {
     pub indices: Vec<u32>,
 }
 
+/// Fill the spare capacity of `out`, returning the number of results available
 impl<G: Graphics> Clone for Session<G> {
     fn clone(&self) -> Self {
         Self {
@@ -378,6 +1488,8 @@ impl<G: Graphics> Clone for Session<G> {
 pub(crate) struct SessionInner {
     pub(crate) instance: Instance,
     pub(crate) handle: sys::Session,
+    /// Secondary view configurations enabled by the most recent `begin`
+    pub(crate) secondary_view_configurations: Mutex<Vec<ViewConfigurationType>>,
 }
 
 impl Drop for SessionInner {
@@ -407,6 +1519,94 @@ pub struct View {
     pub fov: Fovf,
 }
 
+impl View {
+    /// Column-major projection matrix for this view's field of view
+    ///
+    /// Views look down -Z in a right-handed space, and clip space has +Y up, as in OpenGL and
+    /// Direct3D; Vulkan renderers should additionally flip Y, e.g. with a negative viewport height.
+    /// A `far_z` less than or equal to `near_z` places the far plane at infinity.
+    ///
+    /// ```
+    /// # use openxr::{ClipDepth, Fovf, Posef, View};
+    /// let angle = std::f32::consts::FRAC_PI_4;
+    /// let view = View {
+    ///     pose: Posef::IDENTITY,
+    ///     fov: Fovf { angle_left: -angle, angle_right: angle, angle_up: angle, angle_down: -angle },
+    /// };
+    /// let m = view.projection_matrix(0.1, 100.0, ClipDepth::ZeroToOne);
+    /// // A point on the near plane lands at depth 0
+    /// let (z, w) = (m[10] * -0.1 + m[14], m[11] * -0.1 + m[15]);
+    /// assert!((z / w).abs() < 1e-6);
+    /// ```
+    pub fn projection_matrix(&self, near_z: f32, far_z: f32, depth: ClipDepth) -> [f32; 16] {
+        // Offsets the near plane to -1 in OpenGL's clip space
+        let offset_z = match depth {
+            ClipDepth::NegativeOneToOne => near_z,
+            ClipDepth::ZeroToOne => 0.0,
+        };
+        let (z_scale, z_offset) = if far_z <= near_z {
+            (-1.0, -(near_z + offset_z))
+        } else {
+            (
+                -(far_z + offset_z) / (far_z - near_z),
+                -(far_z * (near_z + offset_z)) / (far_z - near_z),
+            )
+        };
+        self.projection_matrix_with(z_scale, z_offset)
+    }
+
+    /// Like `projection_matrix`, but mapping `near_z` to depth 1 and `far_z` to depth 0
+    ///
+    /// Distributes depth precision more evenly when used with a floating-point depth buffer and a
+    /// `[0, 1]` clip depth range, i.e. `ClipDepth::ZeroToOne`. A `far_z` less than or equal to
+    /// `near_z` places the far plane at infinity.
+    pub fn projection_matrix_reversed_z(&self, near_z: f32, far_z: f32) -> [f32; 16] {
+        let (z_scale, z_offset) = if far_z <= near_z {
+            (0.0, near_z)
+        } else {
+            (near_z / (far_z - near_z), far_z * near_z / (far_z - near_z))
+        };
+        self.projection_matrix_with(z_scale, z_offset)
+    }
+
+    // Private helper
+    fn projection_matrix_with(&self, z_scale: f32, z_offset: f32) -> [f32; 16] {
+        let left = self.fov.angle_left.tan();
+        let right = self.fov.angle_right.tan();
+        let up = self.fov.angle_up.tan();
+        let down = self.fov.angle_down.tan();
+        let width = right - left;
+        let height = up - down;
+        [
+            2.0 / width,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+            2.0 / height,
+            0.0,
+            0.0,
+            (right + left) / width,
+            (up + down) / height,
+            z_scale,
+            -1.0,
+            0.0,
+            0.0,
+            z_offset,
+            0.0,
+        ]
+    }
+}
+
+/// Range of depth values in clip space, after the perspective divide
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ClipDepth {
+    /// `[-1, 1]`, as in OpenGL by default
+    NegativeOneToOne,
+    /// `[0, 1]`, as in Direct3D and Vulkan
+    ZeroToOne,
+}
+
 #[repr(transparent)]
 #[derive(Copy, Clone)]
 pub struct ActiveActionSet<'a> {
@@ -441,31 +1641,81 @@ impl<'a> From<&'a ActionSet> for ActiveActionSet<'a> {
 /// Handle for waiting to render a frame
 pub struct FrameWaiter {
     session: Arc<SessionInner>,
+    /// Most recent positive display period reported by the runtime
+    last_display_period: Option<Duration>,
 }
 
 impl FrameWaiter {
     fn new<G: Graphics>(session: Session<G>) -> Self {
         Self {
             session: session.inner,
+            last_display_period: None,
         }
     }
 
     /// Block until rendering should begin, and return details to guide rendering
+    ///
+    /// If the session was begun with `Session::begin_with_secondary`, use `wait_secondary`
+    /// instead.
     #[inline]
     pub fn wait(&mut self) -> Result<FrameState> {
-        let out = unsafe {
-            let mut x = sys::FrameState::out(ptr::null_mut());
-            cvt((self.session.instance.fp().wait_frame)(
+        let out = unsafe { self.wait_raw(ptr::null_mut())? };
+        Ok(FrameState::new(&out, &mut self.last_display_period))
+    }
+
+    /// Like `wait`, but also report which of the secondary view configurations enabled by
+    /// `Session::begin_with_secondary` are currently active
+    pub fn wait_secondary(&mut self) -> Result<(FrameState, Vec<SecondaryViewConfigurationState>)> {
+        let secondary_types = self
+            .session
+            .secondary_view_configurations
+            .lock()
+            .unwrap()
+            .clone();
+        let mut secondary_states = secondary_types
+            .iter()
+            .map(|&ty| sys::SecondaryViewConfigurationStateMSFT {
+                ty: sys::SecondaryViewConfigurationStateMSFT::TYPE,
+                next: ptr::null_mut(),
+                view_configuration_type: ty,
+                active: false.into(),
+            })
+            .collect::<Vec<_>>();
+        let mut secondary = sys::SecondaryViewConfigurationFrameStateMSFT {
+            ty: sys::SecondaryViewConfigurationFrameStateMSFT::TYPE,
+            next: ptr::null_mut(),
+            view_configuration_count: secondary_states.len() as u32,
+            view_configuration_states: secondary_states.as_mut_ptr(),
+        };
+        let next = if secondary_states.is_empty() {
+            ptr::null_mut()
+        } else {
+            &mut secondary as *mut _ as _
+        };
+        let out = unsafe { self.wait_raw(next)? };
+        let secondary_states = secondary_states
+            .into_iter()
+            .map(|x| SecondaryViewConfigurationState {
+                view_configuration_type: x.view_configuration_type,
+                active: x.active.into(),
+            })
+            .collect();
+        Ok((
+            FrameState::new(&out, &mut self.last_display_period),
+            secondary_states,
+        ))
+    }
+
+    unsafe fn wait_raw(&mut self, next: *mut std::ffi::c_void) -> Result<sys::FrameState> {
+        let mut x = sys::FrameState::out(next as _);
+        cvt(
+            "xrWaitFrame",
+            (self.session.instance.fp().wait_frame)(
                 self.session.handle,
                 ptr::null(),
                 x.as_mut_ptr(),
-            ))?;
-            x.assume_init()
-        };
-        Ok(FrameState {
-            predicted_display_time: out.predicted_display_time,
-            predicted_display_period: out.predicted_display_period,
-            should_render: out.should_render.into(),
-        })
+            ),
+        )?;
+        Ok(x.assume_init())
     }
 }
@@ -0,0 +1,112 @@
+use crate::*;
+
+/// Tracks the lifecycle of a `Session` and reports when the application must act on it
+///
+/// This is an optional helper; a session may equally be driven by handling
+/// `Event::SessionStateChanged` directly. Feed every event from `Instance::poll_event` into
+/// `update`, and render frames while `is_running` returns true.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn dummy<G: openxr::Graphics>(instance: &openxr::Instance, session: &openxr::Session<G>) {
+/// let mut machine = openxr::SessionStateMachine::new();
+/// let mut buffer = openxr::EventDataBuffer::new();
+/// loop {
+///     while let Some(event) = instance.poll_event(&mut buffer).unwrap() {
+///         match machine.update(&event) {
+///             Some(openxr::SessionAction::Begin) => {
+///                 session
+///                     .begin(openxr::ViewConfigurationType::PRIMARY_STEREO)
+///                     .unwrap();
+///             }
+///             Some(openxr::SessionAction::End) => {
+///                 session.end().unwrap();
+///             }
+///             Some(openxr::SessionAction::Exit) => return,
+///             None => {}
+///         }
+///     }
+///     if machine.is_running() {
+///         // wait for, render, and submit a frame...
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct SessionStateMachine {
+    state: SessionState,
+    running: bool,
+}
+
+impl SessionStateMachine {
+    /// Construct a state machine for a newly created session
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            state: SessionState::UNKNOWN,
+            running: false,
+        }
+    }
+
+    /// The most recent state reported by the runtime
+    #[inline]
+    pub fn state(&self) -> SessionState {
+        self.state
+    }
+
+    /// Whether the session has begun and not yet ended, i.e. whether frames should be submitted
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Process an event, returning the action the application must take in response, if any
+    ///
+    /// `SessionAction::Begin` is only returned once the session is `READY`, and
+    /// `SessionAction::End` only once a running session is `STOPPING`, so following these never
+    /// calls `Session::begin` or `Session::end` in the wrong state.
+    pub fn update(&mut self, event: &Event<'_>) -> Option<SessionAction> {
+        let state = match *event {
+            Event::SessionStateChanged(ref e) => e.state(),
+            Event::InstanceLossPending(_) => {
+                self.running = false;
+                return Some(SessionAction::Exit);
+            }
+            _ => return None,
+        };
+        self.state = state;
+        match state {
+            SessionState::READY if !self.running => {
+                self.running = true;
+                Some(SessionAction::Begin)
+            }
+            SessionState::STOPPING if self.running => {
+                self.running = false;
+                Some(SessionAction::End)
+            }
+            SessionState::EXITING | SessionState::LOSS_PENDING => {
+                self.running = false;
+                Some(SessionAction::Exit)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for SessionStateMachine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An action the application must take to advance a session's lifecycle
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SessionAction {
+    /// Call `Session::begin`, then start submitting frames
+    Begin,
+    /// Stop submitting frames, then call `Session::end`
+    End,
+    /// Destroy the session; with `LOSS_PENDING` or instance loss, a new one may be created later
+    Exit,
+}
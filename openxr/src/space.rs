@@ -3,7 +3,7 @@ use std::{ffi::CString, ptr, sync::Arc};
 use crate::*;
 
 pub struct Space {
-    session: Arc<session::SessionInner>,
+    pub(crate) session: Arc<session::SessionInner>,
     _action_guard: Option<Action<Posef>>,
     handle: sys::Space,
 }
@@ -41,6 +41,19 @@ impl Space {
         }
     }
 
+    /// Take ownership of a space handle created from some object other than an action
+    #[inline]
+    pub(crate) unsafe fn from_inner(
+        session: Arc<session::SessionInner>,
+        handle: sys::Space,
+    ) -> Self {
+        Self {
+            session,
+            _action_guard: None,
+            handle,
+        }
+    }
+
     /// Access the raw swapchain handle
     #[inline]
     pub fn as_raw(&self) -> sys::Space {
@@ -67,10 +80,10 @@ impl Space {
                 object_name: name.as_ptr(),
             };
             unsafe {
-                cvt((fp.set_debug_utils_object_name)(
-                    self.instance().as_raw(),
-                    &info,
-                ))?;
+                cvt(
+                    "xrSetDebugUtilsObjectNameEXT",
+                    (fp.set_debug_utils_object_name)(self.instance().as_raw(), &info),
+                )?;
             }
         }
         Ok(())
@@ -85,12 +98,10 @@ impl Space {
                    "`self` and `base` must have been created, allocated, or retrieved from the same `Session`");
         let out = unsafe {
             let mut x = sys::SpaceLocation::out(ptr::null_mut());
-            cvt((self.fp().locate_space)(
-                self.as_raw(),
-                base.as_raw(),
-                time,
-                x.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrLocateSpace",
+                (self.fp().locate_space)(self.as_raw(), base.as_raw(), time, x.as_mut_ptr()),
+            )?;
             x.assume_init()
         };
         Ok(SpaceLocation {
@@ -101,6 +112,9 @@ impl Space {
 
     /// Determine the location and velocity of a space relative to a base space at a specified time,
     /// if currently known by the runtime.
+    ///
+    /// Velocities are only meaningful when the corresponding bits of
+    /// `SpaceVelocity::velocity_flags` are set.
     #[inline]
     pub fn relate(&self, base: &Space, time: Time) -> Result<(SpaceLocation, SpaceVelocity)> {
         // This assert allows this function to be safe.
@@ -109,12 +123,10 @@ impl Space {
         let (location, velocity) = unsafe {
             let mut velocity = sys::SpaceVelocity::out(ptr::null_mut());
             let mut location = sys::SpaceLocation::out(&mut velocity as *mut _ as _);
-            cvt((self.fp().locate_space)(
-                self.as_raw(),
-                base.as_raw(),
-                time,
-                location.as_mut_ptr(),
-            ))?;
+            cvt(
+                "xrLocateSpace",
+                (self.fp().locate_space)(self.as_raw(), base.as_raw(), time, location.as_mut_ptr()),
+            )?;
             (location.assume_init(), velocity.assume_init())
         };
         Ok((
@@ -130,11 +142,198 @@ impl Space {
         ))
     }
 
+    /// Like `locate`, but also reports the time at which the eye gaze was sampled
+    ///
+    /// `self` must be an action space for a pose action bound to
+    /// `/user/eyes_ext/input/gaze_ext/pose` with `XR_EXT_eye_gaze_interaction` enabled. The pose is
+    /// only valid when the corresponding bits of `SpaceLocation::location_flags` are set, in which
+    /// case the returned time is the time of the gaze sample it was derived from.
+    #[inline]
+    pub fn locate_eye_gaze(&self, base: &Space, time: Time) -> Result<(SpaceLocation, Time)> {
+        // This assert allows this function to be safe.
+        assert_eq!(&*self.session as *const session::SessionInner, &*base.session as *const session::SessionInner,
+                   "`self` and `base` must have been created, allocated, or retrieved from the same `Session`");
+        let (location, sample_time) = unsafe {
+            let mut sample_time = sys::EyeGazeSampleTimeEXT::out(ptr::null_mut());
+            let mut location = sys::SpaceLocation::out(&mut sample_time as *mut _ as _);
+            cvt(
+                "xrLocateSpace",
+                (self.fp().locate_space)(self.as_raw(), base.as_raw(), time, location.as_mut_ptr()),
+            )?;
+            (location.assume_init(), sample_time.assume_init())
+        };
+        Ok((
+            SpaceLocation {
+                location_flags: location.location_flags,
+                pose: location.pose,
+            },
+            sample_time.time,
+        ))
+    }
+
+    /// Get the UUID identifying this space across sessions
+    ///
+    /// Requires `XR_FB_spatial_entity`.
+    #[inline]
+    pub fn uuid(&self) -> Result<sys::UuidEXT> {
+        let fp = self.spatial_entity_fp()?;
+        let mut out = sys::UuidEXT {
+            data: [0; sys::UUID_SIZE],
+        };
+        unsafe {
+            cvt(
+                "xrGetSpaceUuidFB",
+                (fp.get_space_uuid)(self.as_raw(), &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Get the components this space supports, e.g. `LOCATABLE` and `STORABLE`
+    ///
+    /// Requires `XR_FB_spatial_entity`.
+    #[inline]
+    pub fn enumerate_supported_components(&self) -> Result<Vec<SpaceComponentTypeFB>> {
+        let fp = self.spatial_entity_fp()?;
+        get_arr_trusted(
+            "xrEnumerateSpaceSupportedComponentsFB",
+            |cap, count, buf| unsafe {
+                (fp.enumerate_space_supported_components)(self.as_raw(), cap, count, buf)
+            },
+        )
+    }
+
+    /// Get whether a component of this space is enabled
+    ///
+    /// Fails with `ERROR_SPACE_COMPONENT_NOT_SUPPORTED_FB` unless `component_type` is among those
+    /// returned by `enumerate_supported_components`. Requires `XR_FB_spatial_entity`.
+    #[inline]
+    pub fn get_component_status(
+        &self,
+        component_type: SpaceComponentTypeFB,
+    ) -> Result<SpaceComponentStatus> {
+        let fp = self.spatial_entity_fp()?;
+        let out = unsafe {
+            let mut x = sys::SpaceComponentStatusFB::out(ptr::null_mut());
+            cvt(
+                "xrGetSpaceComponentStatusFB",
+                (fp.get_space_component_status)(self.as_raw(), component_type, x.as_mut_ptr()),
+            )?;
+            x.assume_init()
+        };
+        Ok(SpaceComponentStatus {
+            enabled: out.enabled.into(),
+            change_pending: out.change_pending.into(),
+        })
+    }
+
+    /// Begin enabling or disabling a component of this space, e.g. `STORABLE` before `save`
+    ///
+    /// Completion is reported by `Event::SpaceSetStatusCompleteFB` carrying the returned request
+    /// ID. Requires `XR_FB_spatial_entity`.
+    #[inline]
+    pub fn set_component_status(
+        &self,
+        component_type: SpaceComponentTypeFB,
+        enabled: bool,
+        timeout: Duration,
+    ) -> Result<AsyncRequestIdFB> {
+        let fp = self.spatial_entity_fp()?;
+        let info = sys::SpaceComponentStatusSetInfoFB {
+            ty: sys::SpaceComponentStatusSetInfoFB::TYPE,
+            next: ptr::null(),
+            component_type,
+            enabled: enabled.into(),
+            timeout,
+        };
+        let mut out = AsyncRequestIdFB::default();
+        unsafe {
+            cvt(
+                "xrSetSpaceComponentStatusFB",
+                (fp.set_space_component_status)(self.as_raw(), &info, &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Begin persisting this space to `location`, so it can be found by `Session::query_spaces`
+    /// in later sessions
+    ///
+    /// The space's `STORABLE` component must be enabled. Completion is reported by
+    /// `Event::SpaceSaveCompleteFB` carrying the returned request ID. Requires
+    /// `XR_FB_spatial_entity_storage`.
+    #[inline]
+    pub fn save(
+        &self,
+        location: SpaceStorageLocationFB,
+        persistence_mode: SpacePersistenceModeFB,
+    ) -> Result<AsyncRequestIdFB> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_spatial_entity_storage
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::SpaceSaveInfoFB {
+            ty: sys::SpaceSaveInfoFB::TYPE,
+            next: ptr::null(),
+            space: self.as_raw(),
+            location,
+            persistence_mode,
+        };
+        let mut out = AsyncRequestIdFB::default();
+        unsafe {
+            cvt(
+                "xrSaveSpaceFB",
+                (fp.save_space)(self.session.handle, &info, &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
+    /// Begin removing this space from `location`
+    ///
+    /// Completion is reported by `Event::SpaceEraseCompleteFB` carrying the returned request ID.
+    /// Requires `XR_FB_spatial_entity_storage`.
+    #[inline]
+    pub fn erase(&self, location: SpaceStorageLocationFB) -> Result<AsyncRequestIdFB> {
+        let fp = self
+            .instance()
+            .exts()
+            .fb_spatial_entity_storage
+            .as_ref()
+            .ok_or(sys::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+        let info = sys::SpaceEraseInfoFB {
+            ty: sys::SpaceEraseInfoFB::TYPE,
+            next: ptr::null(),
+            space: self.as_raw(),
+            location,
+        };
+        let mut out = AsyncRequestIdFB::default();
+        unsafe {
+            cvt(
+                "xrEraseSpaceFB",
+                (fp.erase_space)(self.session.handle, &info, &mut out),
+            )?;
+        }
+        Ok(out)
+    }
+
     // Private helper
     #[inline]
     fn fp(&self) -> &raw::Instance {
         self.session.instance.fp()
     }
+
+    // Private helper
+    #[inline]
+    fn spatial_entity_fp(&self) -> Result<&raw::SpatialEntityFB> {
+        self.instance()
+            .exts()
+            .fb_spatial_entity
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
 }
 
 impl Drop for Space {
@@ -145,15 +344,86 @@ impl Drop for Space {
     }
 }
 
+/// Position and orientation of a `Space` relative to some base `Space`
+///
+/// A valid but untracked pose is the runtime's best guess, e.g. the last known position of a
+/// controller that has lost tracking, and should be treated as stale.
 #[derive(Copy, Clone)]
 pub struct SpaceLocation {
     pub location_flags: SpaceLocationFlags,
     pub pose: Posef,
 }
 
+impl SpaceLocation {
+    /// Whether both the position and orientation of `pose` hold valid data
+    #[inline]
+    pub fn pose_valid(&self) -> bool {
+        self.location_flags
+            .contains(SpaceLocationFlags::POSITION_VALID | SpaceLocationFlags::ORIENTATION_VALID)
+    }
+
+    /// Whether `pose.orientation` holds valid data
+    #[inline]
+    pub fn orientation_valid(&self) -> bool {
+        self.location_flags
+            .contains(SpaceLocationFlags::ORIENTATION_VALID)
+    }
+
+    /// Whether `pose.position` holds valid data
+    #[inline]
+    pub fn position_valid(&self) -> bool {
+        self.location_flags
+            .contains(SpaceLocationFlags::POSITION_VALID)
+    }
+
+    /// Whether `pose.orientation` is actively tracked rather than inferred
+    #[inline]
+    pub fn orientation_tracked(&self) -> bool {
+        self.location_flags
+            .contains(SpaceLocationFlags::ORIENTATION_TRACKED)
+    }
+
+    /// Whether `pose.position` is actively tracked rather than inferred
+    #[inline]
+    pub fn position_tracked(&self) -> bool {
+        self.location_flags
+            .contains(SpaceLocationFlags::POSITION_TRACKED)
+    }
+}
+
+/// Linear and angular velocity of a `Space` relative to some base `Space`
 #[derive(Copy, Clone)]
 pub struct SpaceVelocity {
+    /// Which of `linear_velocity` and `angular_velocity` hold valid data
     pub velocity_flags: SpaceVelocityFlags,
+    /// Meters per second, valid if `velocity_flags` contains `LINEAR_VALID`
     pub linear_velocity: Vector3f,
+    /// Axis of rotation scaled by radians per second, valid if `velocity_flags` contains
+    /// `ANGULAR_VALID`
     pub angular_velocity: Vector3f,
 }
+
+/// Status of a component of a `Space`, obtained from `Space::get_component_status`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SpaceComponentStatus {
+    /// Whether the component is currently enabled
+    pub enabled: bool,
+    /// Whether a `Space::set_component_status` request for the component is still in progress
+    pub change_pending: bool,
+}
+
+/// Which spaces `Session::query_spaces` should find
+#[derive(Copy, Clone)]
+pub enum SpaceFilter<'a> {
+    /// Spaces with any of the given UUIDs, as obtained from `Space::uuid`
+    Uuids(&'a [sys::UuidEXT]),
+    /// Spaces supporting the given component
+    Component(SpaceComponentTypeFB),
+}
+
+/// A space found by `Session::query_spaces`, obtained from `Session::retrieve_space_query_results`
+pub struct SpaceQueryResult {
+    pub space: Space,
+    /// The same value as `Space::uuid` would return for `space`
+    pub uuid: sys::UuidEXT,
+}
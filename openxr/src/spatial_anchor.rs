@@ -0,0 +1,81 @@
+use std::{ptr, sync::Arc};
+
+use crate::*;
+
+/// A point in the physical world which the runtime keeps track of over time
+///
+/// Created by `Session::create_spatial_anchor`. Requires `XR_MSFT_spatial_anchor`.
+pub struct SpatialAnchor {
+    session: Arc<session::SessionInner>,
+    handle: sys::SpatialAnchorMSFT,
+}
+
+impl SpatialAnchor {
+    /// Take ownership of an existing spatial anchor handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid spatial anchor handle associated with `session`, which must have
+    /// been created with `XR_MSFT_spatial_anchor` enabled.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(
+        session: Session<G>,
+        handle: sys::SpatialAnchorMSFT,
+    ) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+        }
+    }
+
+    /// Access the raw spatial anchor handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::SpatialAnchorMSFT {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// Create a `Space` whose origin is offset from the anchor by `pose`
+    ///
+    /// The space remains valid after the anchor is dropped.
+    #[inline]
+    pub fn create_space(&self, pose: Posef) -> Result<Space> {
+        let info = sys::SpatialAnchorSpaceCreateInfoMSFT {
+            ty: sys::SpatialAnchorSpaceCreateInfoMSFT::TYPE,
+            next: ptr::null(),
+            anchor: self.as_raw(),
+            pose_in_anchor_space: pose,
+        };
+        let mut out = sys::Space::NULL;
+        unsafe {
+            cvt(
+                "xrCreateSpatialAnchorSpaceMSFT",
+                (self.fp().create_spatial_anchor_space)(self.session.handle, &info, &mut out),
+            )?;
+            Ok(Space::from_inner(self.session.clone(), out))
+        }
+    }
+
+    // Private helper
+    #[inline]
+    fn fp(&self) -> &raw::SpatialAnchorMSFT {
+        self.instance()
+            .exts()
+            .msft_spatial_anchor
+            .as_ref()
+            .expect("MSFT_spatial_anchor not loaded")
+    }
+}
+
+impl Drop for SpatialAnchor {
+    fn drop(&mut self) {
+        unsafe {
+            (self.fp().destroy_spatial_anchor)(self.handle);
+        }
+    }
+}
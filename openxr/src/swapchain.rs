@@ -9,6 +9,20 @@ pub struct Swapchain<G: Graphics> {
     _marker: PhantomData<G>,
     /// Whether `wait_image` was called more recently than `release_image`
     waited: bool,
+    /// Parameters the swapchain was created with, if known
+    pub(crate) params: Option<SwapchainParams>,
+}
+
+/// The subset of `sys::SwapchainCreateInfo` describing the images of a `Swapchain`
+#[derive(Copy, Clone)]
+pub(crate) struct SwapchainParams {
+    pub format: i64,
+    pub sample_count: u32,
+    pub width: u32,
+    pub height: u32,
+    pub face_count: u32,
+    pub array_size: u32,
+    pub mip_count: u32,
 }
 
 impl<G: Graphics> Swapchain<G> {
@@ -24,6 +38,7 @@ impl<G: Graphics> Swapchain<G> {
             handle,
             _marker: PhantomData,
             waited: false,
+            params: None,
         }
     }
 
@@ -39,6 +54,66 @@ impl<G: Graphics> Swapchain<G> {
         self.session.instance()
     }
 
+    /// The format of each image
+    ///
+    /// This and the other accessors describing the images return `None` if the swapchain was not
+    /// created by `Session::create_swapchain`.
+    #[inline]
+    pub fn format(&self) -> Option<G::Format> {
+        self.params.and_then(|x| G::raise_format(x.format))
+    }
+
+    /// The width of each image, in pixels
+    #[inline]
+    pub fn width(&self) -> Option<u32> {
+        self.params.map(|x| x.width)
+    }
+
+    /// The height of each image, in pixels
+    #[inline]
+    pub fn height(&self) -> Option<u32> {
+        self.params.map(|x| x.height)
+    }
+
+    /// The number of array layers in each image, e.g. 2 for stereo rendering with multiview
+    #[inline]
+    pub fn array_size(&self) -> Option<u32> {
+        self.params.map(|x| x.array_size)
+    }
+
+    /// The number of samples per pixel in each image
+    #[inline]
+    pub fn sample_count(&self) -> Option<u32> {
+        self.params.map(|x| x.sample_count)
+    }
+
+    /// The number of mip levels in each image
+    #[inline]
+    pub fn mip_count(&self) -> Option<u32> {
+        self.params.map(|x| x.mip_count)
+    }
+
+    /// The number of faces in each image, e.g. 6 for cubemaps
+    #[inline]
+    pub fn face_count(&self) -> Option<u32> {
+        self.params.map(|x| x.face_count)
+    }
+
+    /// The entire area of each image, for use as a `SwapchainSubImage::image_rect`
+    ///
+    /// `SwapchainSubImage::swapchain` uses this unless an `image_rect` was set first. `None` if the
+    /// swapchain was not created by `Session::create_swapchain`.
+    #[inline]
+    pub fn full_rect(&self) -> Option<Rect2Di> {
+        self.params.map(|x| Rect2Di {
+            offset: Offset2Di { x: 0, y: 0 },
+            extent: Extent2Di {
+                width: x.width as i32,
+                height: x.height as i32,
+            },
+        })
+    }
+
     /// Set the debug name of this `Swapchain`, if `XR_EXT_debug_utils` is loaded
     #[inline]
     pub fn set_name(&mut self, name: &str) -> Result<()> {
@@ -53,15 +128,19 @@ impl<G: Graphics> Swapchain<G> {
                 object_name: name.as_ptr(),
             };
             unsafe {
-                cvt((fp.set_debug_utils_object_name)(
-                    self.instance().as_raw(),
-                    &info,
-                ))?;
+                cvt(
+                    "xrSetDebugUtilsObjectNameEXT",
+                    (fp.set_debug_utils_object_name)(self.instance().as_raw(), &info),
+                )?;
             }
         }
         Ok(())
     }
 
+    /// Access the images allocated by the runtime for this swapchain
+    ///
+    /// The index returned by `acquire_image` refers to an element of this array. The images
+    /// remain valid for the lifetime of the swapchain.
     #[inline]
     pub fn enumerate_images(&self) -> Result<Vec<G::SwapchainImage>> {
         G::enumerate_swapchain_images(self)
@@ -72,18 +151,20 @@ impl<G: Graphics> Swapchain<G> {
     pub fn acquire_image(&mut self) -> Result<u32> {
         let mut out = 0;
         unsafe {
-            cvt((self.fp().acquire_swapchain_image)(
-                self.as_raw(),
-                ptr::null(),
-                &mut out,
-            ))?;
+            cvt(
+                "xrAcquireSwapchainImage",
+                (self.fp().acquire_swapchain_image)(self.as_raw(), ptr::null(), &mut out),
+            )?;
         }
         Ok(out)
     }
 
     /// Wait for the compositor to finish reading from the oldest unwaited acquired image
+    ///
+    /// Returns `false` if `timeout` elapsed before the image became available, in which case the
+    /// image has not been waited on and this call may be retried.
     #[inline]
-    pub fn wait_image(&mut self, timeout: Duration) -> Result<()> {
+    pub fn wait_image(&mut self, timeout: Duration) -> Result<bool> {
         assert!(
             !self.waited,
             "release_image must be called before wait_image can be called again"
@@ -93,14 +174,23 @@ impl<G: Graphics> Swapchain<G> {
             next: ptr::null_mut(),
             timeout,
         };
-        unsafe {
-            cvt((self.fp().wait_swapchain_image)(self.as_raw(), &info))?;
+        let status = unsafe {
+            cvt(
+                "xrWaitSwapchainImage",
+                (self.fp().wait_swapchain_image)(self.as_raw(), &info),
+            )?
+        };
+        if status == sys::Result::TIMEOUT_EXPIRED {
+            return Ok(false);
         }
         self.waited = true;
-        Ok(())
+        Ok(true)
     }
 
     /// Release the oldest acquired image
+    ///
+    /// Panics if the image was not successfully waited on with `wait_image`, which in turn requires
+    /// that it was acquired with `acquire_image`.
     #[inline]
     pub fn release_image(&mut self) -> Result<()> {
         assert!(
@@ -108,15 +198,72 @@ impl<G: Graphics> Swapchain<G> {
             "wait_image must be called before release_image"
         );
         unsafe {
-            cvt((self.fp().release_swapchain_image)(
-                self.as_raw(),
-                ptr::null(),
-            ))?;
+            cvt(
+                "xrReleaseSwapchainImage",
+                (self.fp().release_swapchain_image)(self.as_raw(), ptr::null()),
+            )?;
         }
         self.waited = false;
         Ok(())
     }
 
+    /// Update mutable state of the swapchain, such as its foveation profile
+    ///
+    /// `state` may be any of the `SwapchainState*FB` builders. Prefer `update_sampler` for sampler
+    /// state, which only accepts the builder matching the swapchain's graphics API.
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_swapchain_update_state` was not enabled on
+    /// the instance.
+    #[inline]
+    pub fn update_state(&mut self, state: &SwapchainStateBaseFB<'_>) -> Result<()> {
+        let fp = self.update_state_fp()?;
+        unsafe {
+            cvt(
+                "xrUpdateSwapchainFB",
+                (fp.update_swapchain)(self.as_raw(), state as *const _ as _),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Read mutable state of the swapchain into `state`, whose `ty` selects the state to read
+    ///
+    /// Returns `ERROR_EXTENSION_NOT_PRESENT` if `XR_FB_swapchain_update_state` was not enabled on
+    /// the instance.
+    ///
+    /// # Safety
+    ///
+    /// `state` must point to a valid structure of the type identified by its `ty`, whose `next`
+    /// chain is likewise valid.
+    #[inline]
+    pub unsafe fn get_state(&self, state: *mut sys::SwapchainStateBaseHeaderFB) -> Result<()> {
+        let fp = self.update_state_fp()?;
+        cvt(
+            "xrGetSwapchainStateFB",
+            (fp.get_swapchain_state)(self.as_raw(), state),
+        )?;
+        Ok(())
+    }
+
+    /// Apply a foveation profile to the images of this swapchain
+    ///
+    /// The swapchain must have been created with `XR_FB_foveation` enabled.
+    #[inline]
+    pub fn update_foveation(&mut self, profile: &FoveationProfile) -> Result<()> {
+        let state = SwapchainStateFoveationFB::new().profile(profile.as_raw());
+        self.update_state(&state)
+    }
+
+    // Private helper
+    #[inline]
+    fn update_state_fp(&self) -> Result<&raw::SwapchainUpdateStateFB> {
+        self.instance()
+            .exts()
+            .fb_swapchain_update_state
+            .as_ref()
+            .ok_or_else(|| error(None, sys::Result::ERROR_EXTENSION_NOT_PRESENT))
+    }
+
     // Private helper
     #[inline]
     fn fp(&self) -> &raw::Instance {
@@ -124,6 +271,61 @@ impl<G: Graphics> Swapchain<G> {
     }
 }
 
+impl Swapchain<Vulkan> {
+    /// Change how the compositor samples the images, e.g. their filters and wrap modes
+    ///
+    /// Requires `XR_FB_swapchain_update_state_vulkan`. Wrap modes matter most for layers whose
+    /// texture coordinates may leave the image, such as cylinder and equirect layers.
+    ///
+    /// Only available on Vulkan swapchains:
+    ///
+    /// ```compile_fail
+    /// # fn dummy(swapchain: &mut openxr::Swapchain<openxr::OpenGL>) {
+    /// swapchain.update_sampler(&openxr::SwapchainStateSamplerVulkanFB::new());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn update_sampler(&mut self, sampler: &SwapchainStateSamplerVulkanFB<'_>) -> Result<()> {
+        self.update_state(sampler)
+    }
+
+    /// Read how the compositor samples the images
+    ///
+    /// Requires `XR_FB_swapchain_update_state_vulkan`.
+    #[inline]
+    pub fn sampler(&self) -> Result<SwapchainStateSamplerVulkanFB<'static>> {
+        let mut out = SwapchainStateSamplerVulkanFB::new();
+        unsafe {
+            self.get_state(&mut out as *mut _ as _)?;
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(all(target_os = "android", feature = "opengles"))]
+impl Swapchain<OpenGlEs> {
+    /// Change how the compositor samples the images, e.g. their filters and wrap modes
+    ///
+    /// Requires `XR_FB_swapchain_update_state_opengl_es`. Wrap modes matter most for layers whose
+    /// texture coordinates may leave the image, such as cylinder and equirect layers.
+    #[inline]
+    pub fn update_sampler(&mut self, sampler: &SwapchainStateSamplerOpenGLESFB<'_>) -> Result<()> {
+        self.update_state(sampler)
+    }
+
+    /// Read how the compositor samples the images
+    ///
+    /// Requires `XR_FB_swapchain_update_state_opengl_es`.
+    #[inline]
+    pub fn sampler(&self) -> Result<SwapchainStateSamplerOpenGLESFB<'static>> {
+        let mut out = SwapchainStateSamplerOpenGLESFB::new();
+        unsafe {
+            self.get_state(&mut out as *mut _ as _)?;
+        }
+        Ok(out)
+    }
+}
+
 impl<G: Graphics> Drop for Swapchain<G> {
     fn drop(&mut self) {
         unsafe {
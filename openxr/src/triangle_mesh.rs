@@ -0,0 +1,138 @@
+use std::{ptr, sync::Arc};
+
+use crate::*;
+
+/// A triangle mesh owned by the runtime, e.g. the geometry of a projected passthrough surface
+///
+/// Created by `Session::create_triangle_mesh`. Requires `XR_FB_triangle_mesh`.
+pub struct TriangleMesh {
+    session: Arc<session::SessionInner>,
+    handle: sys::TriangleMeshFB,
+    /// Maximum number of vertices and triangles, if mutable
+    capacity: Option<(u32, u32)>,
+}
+
+impl TriangleMesh {
+    /// Take ownership of an existing immutable triangle mesh handle
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid triangle mesh handle associated with `session`, which must have
+    /// been created with `XR_FB_triangle_mesh` enabled.
+    #[inline]
+    pub unsafe fn from_raw<G: Graphics>(session: Session<G>, handle: sys::TriangleMeshFB) -> Self {
+        Self {
+            session: session.inner,
+            handle,
+            capacity: None,
+        }
+    }
+
+    /// Access the raw triangle mesh handle
+    #[inline]
+    pub fn as_raw(&self) -> sys::TriangleMeshFB {
+        self.handle
+    }
+
+    /// Access the `Instance` self is descended from
+    #[inline]
+    pub fn instance(&self) -> &Instance {
+        &self.session.instance
+    }
+
+    /// Whether the mesh was created with `TriangleMeshFlagsFB::MUTABLE`, and hence may be
+    /// `update`d
+    #[inline]
+    pub fn is_mutable(&self) -> bool {
+        self.capacity.is_some()
+    }
+
+    /// Replace the contents of a mutable mesh
+    ///
+    /// # Panics
+    ///
+    /// Panics if the mesh is not mutable, if `indices` does not describe whole triangles, or if
+    /// either buffer is larger than the one the mesh was created with.
+    pub fn update(&mut self, vertices: &[Vector3f], indices: &[u32]) -> Result<()> {
+        let (vertex_capacity, triangle_capacity) =
+            self.capacity.expect("only mutable meshes may be updated");
+        assert_eq!(
+            indices.len() % 3,
+            0,
+            "`indices` must describe whole triangles"
+        );
+        assert!(
+            vertices.len() <= vertex_capacity as usize,
+            "`vertices` exceeds the mesh's capacity"
+        );
+        assert!(
+            indices.len() / 3 <= triangle_capacity as usize,
+            "`indices` exceeds the mesh's capacity"
+        );
+        unsafe { self.write(vertices, indices) }
+    }
+
+    /// Mark a newly created mesh as mutable with the given capacity and fill it
+    pub(crate) unsafe fn init_mutable(
+        &mut self,
+        vertices: &[Vector3f],
+        indices: &[u32],
+    ) -> Result<()> {
+        self.capacity = Some((vertices.len() as u32, (indices.len() / 3) as u32));
+        self.write(vertices, indices)
+    }
+
+    /// Copy `vertices` and `indices` into the mesh's buffers, which must be large enough
+    unsafe fn write(&mut self, vertices: &[Vector3f], indices: &[u32]) -> Result<()> {
+        let fp = fp(&self.session);
+        cvt(
+            "xrTriangleMeshBeginUpdateFB",
+            (fp.triangle_mesh_begin_update)(self.handle),
+        )?;
+        let mut vertex_buffer = ptr::null_mut();
+        let mut index_buffer = ptr::null_mut();
+        let result = cvt(
+            "xrTriangleMeshGetVertexBufferFB",
+            (fp.triangle_mesh_get_vertex_buffer)(self.handle, &mut vertex_buffer),
+        )
+        .and_then(|_| {
+            cvt(
+                "xrTriangleMeshGetIndexBufferFB",
+                (fp.triangle_mesh_get_index_buffer)(self.handle, &mut index_buffer),
+            )
+        });
+        let (vertex_count, triangle_count) = match result {
+            Ok(_) => {
+                ptr::copy_nonoverlapping(vertices.as_ptr(), vertex_buffer, vertices.len());
+                ptr::copy_nonoverlapping(indices.as_ptr(), index_buffer, indices.len());
+                (vertices.len() as u32, (indices.len() / 3) as u32)
+            }
+            // Leave the mesh empty rather than half-written
+            Err(_) => (0, 0),
+        };
+        cvt(
+            "xrTriangleMeshEndUpdateFB",
+            (fp.triangle_mesh_end_update)(self.handle, vertex_count, triangle_count),
+        )?;
+        result.map(|_| ())
+    }
+}
+
+impl Drop for TriangleMesh {
+    fn drop(&mut self) {
+        unsafe {
+            (fp(&self.session).destroy_triangle_mesh)(self.handle);
+        }
+    }
+}
+
+// Private helper
+#[inline]
+fn fp(session: &session::SessionInner) -> &raw::TriangleMeshFB {
+    session
+        .instance
+        .exts()
+        .fb_triangle_mesh
+        .as_ref()
+        .expect("FB_triangle_mesh not loaded")
+}
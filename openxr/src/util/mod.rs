@@ -0,0 +1,3 @@
+//! Helpers for binding OpenXR functionality that this crate does not wrap, e.g. extensions
+
+pub mod two_call;
@@ -0,0 +1,212 @@
+//! Drivers for the OpenXR two-call idiom, used by functions that fill a caller-allocated buffer
+//!
+//! Each helper takes a `getter` closure that forwards its arguments to the underlying function as
+//! the capacity input, count output, and buffer pointer respectively. The buffer is first sized
+//! by a call with zero capacity, then filled, and regrown if the runtime reports
+//! `ERROR_SIZE_INSUFFICIENT` because the required size changed in between.
+//!
+//! Counts reported by `getter` are not trusted: a count exceeding the capacity it was given fails
+//! with `ERROR_RUNTIME_FAILURE` rather than exposing uninitialized memory, as does a string that is
+//! not valid UTF-8.
+
+use std::os::raw::c_char;
+
+use crate::*;
+
+/// Retrieve a null-terminated UTF-8 string, as returned by e.g. `xrPathToString`
+///
+/// # Example
+///
+/// ```
+/// # use openxr::{sys, util::two_call::get_str};
+/// let name = b"/user/hand/left\0";
+/// let s = get_str("xrPathToString", |capacity, count, buf| {
+///     *count = name.len() as u32;
+///     if capacity == 0 {
+///         return sys::Result::SUCCESS;
+///     }
+///     unsafe {
+///         std::ptr::copy_nonoverlapping(name.as_ptr() as *const _, buf, name.len());
+///     }
+///     sys::Result::SUCCESS
+/// })
+/// .unwrap();
+/// assert_eq!(s, "/user/hand/left");
+///
+/// // An empty result is an empty string
+/// let s = get_str("xrPathToString", |_, count, _| {
+///     *count = 0;
+///     sys::Result::SUCCESS
+/// });
+/// assert_eq!(s.unwrap(), "");
+/// ```
+pub fn get_str(
+    function: &'static str,
+    mut getter: impl FnMut(u32, &mut u32, *mut c_char) -> sys::Result,
+) -> Result<String> {
+    let mut bytes = get_arr(function, |x, y, z| getter(x, y, z as _))?;
+    // Strip null byte, if any
+    if bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    String::from_utf8(bytes).map_err(|_| error(Some(function), sys::Result::ERROR_RUNTIME_FAILURE))
+}
+
+/// Like `get_str`, but trusting `getter` to report a null-terminated UTF-8 string
+pub(crate) fn get_str_trusted(
+    function: &'static str,
+    mut getter: impl FnMut(u32, &mut u32, *mut c_char) -> sys::Result,
+) -> Result<String> {
+    let mut bytes = get_arr_trusted(function, |x, y, z| getter(x, y, z as _))?;
+    // Strip null byte
+    bytes.truncate(bytes.len() - 1);
+    unsafe { Ok(String::from_utf8_unchecked(bytes)) }
+}
+
+/// Retrieve an array of elements which need not be initialized before the call
+///
+/// # Example
+///
+/// ```
+/// # use openxr::{sys, util::two_call::get_arr};
+/// let items = [1u32, 2, 3, 4, 5];
+/// // The runtime gains more items after reporting the required capacity
+/// let mut available = 3;
+/// let mut calls = 0;
+/// let out = get_arr("xrEnumerateExample", |capacity, count, buf| {
+///     calls += 1;
+///     *count = available;
+///     available = items.len() as u32;
+///     if capacity == 0 {
+///         return sys::Result::SUCCESS;
+///     }
+///     if capacity < *count {
+///         return sys::Result::ERROR_SIZE_INSUFFICIENT;
+///     }
+///     unsafe {
+///         std::ptr::copy_nonoverlapping(items.as_ptr(), buf, *count as usize);
+///     }
+///     sys::Result::SUCCESS
+/// })
+/// .unwrap();
+/// assert_eq!(out, items);
+/// assert_eq!(calls, 3);
+///
+/// // Claiming to have written more than fits is an error, not undefined behavior
+/// let result = get_arr::<u32>("xrEnumerateExample", |capacity, count, _| {
+///     *count = capacity + 1;
+///     sys::Result::SUCCESS
+/// });
+/// assert_eq!(result.unwrap_err(), sys::Result::ERROR_RUNTIME_FAILURE);
+/// ```
+pub fn get_arr<T: Copy>(
+    function: &'static str,
+    getter: impl FnMut(u32, &mut u32, *mut T) -> sys::Result,
+) -> Result<Vec<T>> {
+    let (mut buffer, count) = fill(function, getter)?;
+    if count > buffer.capacity() {
+        return Err(error(Some(function), sys::Result::ERROR_RUNTIME_FAILURE));
+    }
+    unsafe {
+        buffer.set_len(count);
+    }
+    Ok(buffer)
+}
+
+/// Like `get_arr`, but trusting `getter` to report a count no greater than the capacity it was
+/// given, as conforming runtimes do
+pub(crate) fn get_arr_trusted<T: Copy>(
+    function: &'static str,
+    getter: impl FnMut(u32, &mut u32, *mut T) -> sys::Result,
+) -> Result<Vec<T>> {
+    let (mut buffer, count) = fill(function, getter)?;
+    unsafe {
+        buffer.set_len(count);
+    }
+    Ok(buffer)
+}
+
+/// Fill the spare capacity of a buffer, returning it along with the count reported by `getter`
+fn fill<T: Copy>(
+    function: &'static str,
+    mut getter: impl FnMut(u32, &mut u32, *mut T) -> sys::Result,
+) -> Result<(Vec<T>, usize)> {
+    let mut output = 0;
+    cvt(function, getter(0, &mut output, std::ptr::null_mut()))?;
+    let mut buffer = Vec::with_capacity(output as usize);
+    loop {
+        match cvt(
+            function,
+            getter(
+                buffer.capacity() as u32,
+                &mut output,
+                buffer.as_mut_ptr() as _,
+            ),
+        ) {
+            Ok(_) => {
+                return Ok((buffer, output as usize));
+            }
+            Err(e) if e == sys::Result::ERROR_SIZE_INSUFFICIENT => {
+                // `buffer` is empty, so this guarantees a capacity of at least `output`
+                buffer.reserve(output as usize);
+            }
+            Err(e) => {
+                return Err(e);
+            }
+        }
+    }
+}
+
+/// Retrieve an array of elements which must be initialized before the call, e.g. because they
+/// carry a structure type tag
+///
+/// # Example
+///
+/// ```
+/// # use openxr::{sys, util::two_call::get_arr_init};
+/// let mut available = 1;
+/// let out = get_arr_init("xrEnumerateExample", 0u32, |capacity, count, buf| {
+///     *count = available;
+///     available = 4;
+///     if capacity == 0 {
+///         return sys::Result::SUCCESS;
+///     }
+///     if capacity < *count {
+///         return sys::Result::ERROR_SIZE_INSUFFICIENT;
+///     }
+///     for i in 0..*count as usize {
+///         unsafe {
+///             *buf.add(i) += i as u32;
+///         }
+///     }
+///     sys::Result::SUCCESS
+/// })
+/// .unwrap();
+/// assert_eq!(out, [0, 1, 2, 3]);
+/// ```
+pub fn get_arr_init<T: Copy>(
+    function: &'static str,
+    init: T,
+    mut getter: impl FnMut(u32, &mut u32, *mut T) -> sys::Result,
+) -> Result<Vec<T>> {
+    let mut output = 0;
+    cvt(function, getter(0, &mut output, std::ptr::null_mut()))?;
+    let mut buffer = vec![init; output as usize];
+    loop {
+        match cvt(
+            function,
+            getter(output, &mut output, buffer.as_mut_ptr() as _),
+        ) {
+            Ok(_) => {
+                buffer.truncate(output as usize);
+                return Ok(buffer);
+            }
+            Err(e) if e == sys::Result::ERROR_SIZE_INSUFFICIENT => {
+                buffer.resize(output as usize, init);
+            }
+            Err(e) => {
+                return Err(e);
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@ use std::fmt;
 #[macro_use]
 mod support;
 mod generated;
+mod math;
 pub mod platform;
 
 #[cfg(feature = "mint")]
@@ -41,15 +42,17 @@ impl From<bool> for Bool32 {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(transparent)]
 pub struct Time(i64);
 impl Time {
-    pub fn from_nanos(x: i64) -> Self {
+    /// Construct from a number of nanoseconds since the runtime's arbitrary epoch
+    pub const fn from_nanos(x: i64) -> Self {
         Self(x)
     }
 
-    pub fn as_nanos(self) -> i64 {
+    /// Number of nanoseconds since the runtime's arbitrary epoch
+    pub const fn as_nanos(self) -> i64 {
         self.0
     }
 }
@@ -60,6 +63,7 @@ impl fmt::Debug for Time {
     }
 }
 
+/// The duration elapsed from `other` until `self`, negative if `other` is later
 impl std::ops::Sub<Time> for Time {
     type Output = Duration;
 
@@ -68,15 +72,45 @@ impl std::ops::Sub<Time> for Time {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
+impl std::ops::Add<Duration> for Time {
+    type Output = Time;
+
+    fn add(self, other: Duration) -> Time {
+        Time(self.0.wrapping_add(other.0))
+    }
+}
+
+impl std::ops::Sub<Duration> for Time {
+    type Output = Time;
+
+    fn sub(self, other: Duration) -> Time {
+        Time(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl std::ops::AddAssign<Duration> for Time {
+    fn add_assign(&mut self, other: Duration) {
+        *self = *self + other;
+    }
+}
+
+impl std::ops::SubAssign<Duration> for Time {
+    fn sub_assign(&mut self, other: Duration) {
+        *self = *self - other;
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(transparent)]
 pub struct Duration(i64);
 impl Duration {
-    pub fn from_nanos(x: i64) -> Self {
+    /// Construct from a number of nanoseconds, which may be negative
+    pub const fn from_nanos(x: i64) -> Self {
         Self(x)
     }
 
-    pub fn as_nanos(self) -> i64 {
+    /// Number of nanoseconds, which may be negative
+    pub const fn as_nanos(self) -> i64 {
         self.0
     }
 }
@@ -0,0 +1,213 @@
+//! Minimal vector, quaternion, and pose arithmetic, e.g. for composing the poses passed to OpenXR
+//!
+//! Applications with more involved needs should convert to a dedicated math library, e.g. via the
+//! `mint` feature.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{Posef, Quaternionf, Vector3f};
+
+impl Vector3f {
+    #[inline]
+    pub fn dot(self, other: Vector3f) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[inline]
+    pub fn cross(self, other: Vector3f) -> Vector3f {
+        Vector3f {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    #[inline]
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+}
+
+impl Add for Vector3f {
+    type Output = Vector3f;
+
+    #[inline]
+    fn add(self, other: Vector3f) -> Vector3f {
+        Vector3f {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl Sub for Vector3f {
+    type Output = Vector3f;
+
+    #[inline]
+    fn sub(self, other: Vector3f) -> Vector3f {
+        self + -other
+    }
+}
+
+impl Neg for Vector3f {
+    type Output = Vector3f;
+
+    #[inline]
+    fn neg(self) -> Vector3f {
+        self * -1.0
+    }
+}
+
+impl Mul<f32> for Vector3f {
+    type Output = Vector3f;
+
+    #[inline]
+    fn mul(self, other: f32) -> Vector3f {
+        Vector3f {
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+impl Quaternionf {
+    /// Scale to unit length, as required of orientations
+    #[inline]
+    pub fn normalize(self) -> Quaternionf {
+        let len = (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt();
+        Quaternionf {
+            x: self.x / len,
+            y: self.y / len,
+            z: self.z / len,
+            w: self.w / len,
+        }
+    }
+
+    /// The opposite rotation, if normalized
+    #[inline]
+    pub fn conjugate(self) -> Quaternionf {
+        Quaternionf {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Rotate `v` by this quaternion, which must be normalized
+    pub fn rotate(self, v: Vector3f) -> Vector3f {
+        let u = Vector3f {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        };
+        let t = u.cross(v) * 2.0;
+        v + t * self.w + u.cross(t)
+    }
+}
+
+/// Rotation by `other`, followed by rotation by `self`
+impl Mul for Quaternionf {
+    type Output = Quaternionf;
+
+    #[inline]
+    fn mul(self, other: Quaternionf) -> Quaternionf {
+        let (a, b) = (self, other);
+        Quaternionf {
+            x: a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            y: a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            z: a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+            w: a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+        }
+    }
+}
+
+impl Posef {
+    /// Transform `point` from this pose's space into its parent space
+    #[inline]
+    pub fn transform_point(self, point: Vector3f) -> Vector3f {
+        self.orientation.rotate(point) + self.position
+    }
+
+    /// The pose which undoes this one, e.g. to transform from a parent space into this pose's
+    #[inline]
+    pub fn inverse(self) -> Posef {
+        let orientation = self.orientation.conjugate();
+        Posef {
+            orientation,
+            position: -orientation.rotate(self.position),
+        }
+    }
+
+    /// Column-major matrix transforming points from this pose's space into its parent space
+    ///
+    /// The view matrix for a `View` is the matrix of its pose's inverse.
+    ///
+    /// ```
+    /// # use openxr_sys::{Posef, Quaternionf, Vector3f};
+    /// let pose = Posef {
+    ///     orientation: Quaternionf::IDENTITY,
+    ///     position: Vector3f { x: 1.0, y: 2.0, z: 3.0 },
+    /// };
+    /// let m = pose.to_matrix();
+    /// assert_eq!(&m[12..], &[1.0, 2.0, 3.0, 1.0]);
+    /// let view = pose.inverse().to_matrix();
+    /// assert_eq!(&view[12..], &[-1.0, -2.0, -3.0, 1.0]);
+    /// ```
+    pub fn to_matrix(self) -> [f32; 16] {
+        let Quaternionf { x, y, z, w } = self.orientation;
+        let p = self.position;
+        [
+            1.0 - 2.0 * (y * y + z * z),
+            2.0 * (x * y + w * z),
+            2.0 * (x * z - w * y),
+            0.0,
+            2.0 * (x * y - w * z),
+            1.0 - 2.0 * (x * x + z * z),
+            2.0 * (y * z + w * x),
+            0.0,
+            2.0 * (x * z + w * y),
+            2.0 * (y * z - w * x),
+            1.0 - 2.0 * (x * x + y * y),
+            0.0,
+            p.x,
+            p.y,
+            p.z,
+            1.0,
+        ]
+    }
+}
+
+/// Compose two poses, such that `a * b` transforms from `b`'s space into `a`'s parent space
+///
+/// ```
+/// # use openxr_sys::{Posef, Quaternionf, Vector3f};
+/// let half = std::f32::consts::FRAC_1_SQRT_2;
+/// // Turned 90 degrees to the left, one meter up
+/// let a = Posef {
+///     orientation: Quaternionf { x: 0.0, y: half, z: 0.0, w: half },
+///     position: Vector3f { x: 0.0, y: 1.0, z: 0.0 },
+/// };
+/// // One meter ahead
+/// let b = Posef {
+///     orientation: Quaternionf::IDENTITY,
+///     position: Vector3f { x: 0.0, y: 0.0, z: -1.0 },
+/// };
+/// let p = (a * b).position;
+/// assert!((p - Vector3f { x: -1.0, y: 1.0, z: 0.0 }).length() < 1e-6);
+/// let p = (a.inverse() * a).position;
+/// assert!(p.length() < 1e-6);
+/// ```
+impl Mul for Posef {
+    type Output = Posef;
+
+    #[inline]
+    fn mul(self, other: Posef) -> Posef {
+        Posef {
+            orientation: self.orientation * other.orientation,
+            position: self.transform_point(other.position),
+        }
+    }
+}
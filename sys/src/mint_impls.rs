@@ -1,7 +1,7 @@
 use crate::{
-    Extent2Df, Extent2Di, Offset2Df, Offset2Di, Quaternionf, Vector2f, Vector3f, Vector4f,
+    Extent2Df, Extent2Di, Offset2Df, Offset2Di, Posef, Quaternionf, Vector2f, Vector3f, Vector4f,
 };
-use mint::{Quaternion, Vector2, Vector3, Vector4};
+use mint::{ColumnMatrix4, Quaternion, Vector2, Vector3, Vector4};
 
 impl From<Vector2f> for Vector2<f32> {
     fn from(v: Vector2f) -> Self {
@@ -117,6 +117,16 @@ impl From<Vector4<f32>> for Vector4f {
     }
 }
 
+/// Components map by name, so OpenXR's `(x, y, z, w)` becomes mint's `v` and `s`
+///
+/// ```
+/// # use openxr_sys::Quaternionf;
+/// let q = Quaternionf { x: 0.1, y: 0.2, z: 0.3, w: 0.9 };
+/// let m = mint::Quaternion::from(q);
+/// assert_eq!((m.v.x, m.v.y, m.v.z, m.s), (0.1, 0.2, 0.3, 0.9));
+/// let q = Quaternionf::from(m);
+/// assert_eq!((q.x, q.y, q.z, q.w), (0.1, 0.2, 0.3, 0.9));
+/// ```
 impl From<Quaternion<f32>> for Quaternionf {
     fn from(q: Quaternion<f32>) -> Self {
         Self {
@@ -140,3 +150,41 @@ impl From<Quaternionf> for Quaternion<f32> {
         }
     }
 }
+
+/// Converts to and from an orientation and position, which may be composed as an isometry by
+/// e.g. glam or nalgebra
+///
+/// ```
+/// # use openxr_sys::{Posef, Quaternionf, Vector3f};
+/// let pose = Posef {
+///     orientation: Quaternionf { x: 0.0, y: 0.6, z: 0.0, w: 0.8 },
+///     position: Vector3f { x: 1.0, y: 2.0, z: 3.0 },
+/// };
+/// let (q, v): (mint::Quaternion<f32>, mint::Vector3<f32>) = pose.into();
+/// assert_eq!((q.v.y, q.s), (0.6, 0.8));
+/// assert_eq!((v.x, v.y, v.z), (1.0, 2.0, 3.0));
+/// let pose = Posef::from((q, v));
+/// assert_eq!((pose.orientation.y, pose.orientation.w), (0.6, 0.8));
+/// assert_eq!((pose.position.x, pose.position.y, pose.position.z), (1.0, 2.0, 3.0));
+/// ```
+impl From<Posef> for (Quaternion<f32>, Vector3<f32>) {
+    fn from(p: Posef) -> Self {
+        (p.orientation.into(), p.position.into())
+    }
+}
+
+impl From<(Quaternion<f32>, Vector3<f32>)> for Posef {
+    fn from((orientation, position): (Quaternion<f32>, Vector3<f32>)) -> Self {
+        Self {
+            orientation: orientation.into(),
+            position: position.into(),
+        }
+    }
+}
+
+/// The matrix transforming points from the pose's space into its parent space, as from `Posef::to_matrix`
+impl From<Posef> for ColumnMatrix4<f32> {
+    fn from(p: Posef) -> Self {
+        p.to_matrix().into()
+    }
+}
@@ -3,7 +3,7 @@
 //! Cast to these from your bindings of choice.
 #![allow(non_camel_case_types)]
 
-use std::os::raw::{c_ulong, c_void};
+use std::os::raw::{c_char, c_ulong, c_void};
 
 // Vulkan
 pub type VkInstance = *const c_void;
@@ -11,6 +11,18 @@ pub type VkPhysicalDevice = *const c_void;
 pub type VkDevice = *const c_void;
 pub type VkImage = u64;
 pub type VkFormat = u32;
+pub type PFN_vkGetInstanceProcAddr =
+    unsafe extern "system" fn(VkInstance, *const c_char) -> Option<unsafe extern "system" fn()>;
+pub type VkInstanceCreateInfo = c_void;
+pub type VkDeviceCreateInfo = c_void;
+pub type VkAllocationCallbacks = c_void;
+pub type VkResult = i32;
+pub type VkImageCreateFlags = u32;
+pub type VkImageUsageFlags = u32;
+pub type VkComponentSwizzle = i32;
+pub type VkFilter = i32;
+pub type VkSamplerMipmapMode = i32;
+pub type VkSamplerAddressMode = i32;
 
 // Xlib
 pub type GLXFBConfig = *mut c_void;
@@ -28,7 +40,26 @@ pub type xcb_glx_context_t = u32;
 // Wayland
 pub type wl_display = c_void;
 
+// EGL
+pub type EGLDisplay = *mut c_void;
+pub type EGLConfig = *mut c_void;
+pub type EGLContext = *mut c_void;
+pub type EGLenum = u32;
+
+// Android
+#[cfg(target_os = "android")]
+pub type jobject = *mut c_void;
+
+// Magic Leap
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MLCoordinateFrameUID {
+    pub data: [u64; 2],
+}
+
 // Win32
+pub type wchar_t = libc::wchar_t;
+pub type IUnknown = c_void;
 #[cfg(windows)]
 pub type ID3D10Device = *const c_void;
 #[cfg(windows)]